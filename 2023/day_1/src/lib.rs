@@ -0,0 +1,229 @@
+use std::convert::Infallible;
+
+use aoc_common::{
+    checked::OrOverflow,
+    generate::{InputRng, Rng, SliceRandom},
+    Answer, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+const DIGIT_MAPPING: [(&str, u32); 18] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// Only the numeric digits, without their spelled out forms.
+const NUMERIC_DIGIT_MAPPING: &[(&str, u32)] = DIGIT_MAPPING.split_at(9).1;
+
+fn find_digit(mut line: &str, mapping: &[(&str, u32)], scan_forwards: bool) -> Option<u32> {
+    while !line.is_empty() {
+        let found = mapping.iter().find(|(text, _digit)| {
+            if scan_forwards {
+                line.starts_with(text)
+            } else {
+                line.ends_with(text)
+            }
+        });
+
+        if let Some((_text, digit)) = found {
+            return Some(*digit);
+        }
+
+        // Dropping a whole character, since lines aren't necessarily ASCII
+        let mut chars = line.chars();
+        if scan_forwards {
+            chars.next();
+        } else {
+            chars.next_back();
+        }
+        line = chars.as_str();
+    }
+
+    None
+}
+
+fn get_calibration_value_with(line: &str, mapping: &[(&str, u32)]) -> Option<u32> {
+    let first_digit = find_digit(line, mapping, true)?;
+    let last_digit = find_digit(line, mapping, false)?;
+
+    Some(first_digit * 10 + last_digit)
+}
+
+fn get_calibration_value(line: &str) -> Option<u32> {
+    get_calibration_value_with(line, &DIGIT_MAPPING)
+}
+
+fn get_numeric_calibration_value(line: &str) -> Option<u32> {
+    get_calibration_value_with(line, NUMERIC_DIGIT_MAPPING)
+}
+
+fn calibration_sum(text: &str, get_value: fn(&str) -> Option<u32>) -> Result<u64, SolveError> {
+    text.lines().try_fold(0u64, |sum, line| {
+        let value = get_value(line).ok_or_else(|| {
+            SolveError::Unsupported("A line has no calibration value".to_string())
+        })?;
+        sum.checked_add(value.into())
+            .or_overflow("the sum of the calibration values")
+    })
+}
+
+fn get_calibration_sum(text: &str) -> Result<u64, SolveError> {
+    calibration_sum(text, get_calibration_value)
+}
+
+fn get_numeric_calibration_sum(text: &str) -> Result<u64, SolveError> {
+    calibration_sum(text, get_numeric_calibration_value)
+}
+
+/// Spelled out digits sharing letters, which are easy to read only one of.
+const OVERLAPPING_DIGITS: &[&str] = &[
+    "oneight",
+    "twone",
+    "threeight",
+    "fiveight",
+    "sevenine",
+    "eightwo",
+    "eighthree",
+    "nineight",
+];
+
+/// Lines of letters, digits and spelled out digits, each with at least one digit so both parts can read it.
+fn generate_calibration_document(lines: usize, rng: &mut InputRng) -> String {
+    let mut document = String::new();
+    for _ in 0..lines {
+        let mut tokens: Vec<String> = (0..rng.gen_range(2..=6))
+            .map(|_| match rng.gen_range(0..4) {
+                0 => (0..rng.gen_range(1..=3))
+                    .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                    .collect(),
+                1 => rng.gen_range(1..=9).to_string(),
+                2 => DIGIT_MAPPING[rng.gen_range(0..9)].0.to_string(),
+                _ => OVERLAPPING_DIGITS.choose(rng).unwrap().to_string(),
+            })
+            .collect();
+        if !tokens
+            .iter()
+            .any(|t| t.starts_with(|c: char| c.is_ascii_digit()))
+        {
+            let at = rng.gen_range(0..=tokens.len());
+            tokens.insert(at, rng.gen_range(1..=9).to_string());
+        }
+        document.push_str(&tokens.concat());
+        document.push('\n');
+    }
+    document
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+    type Error = Infallible;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        get_numeric_calibration_sum(input).map(Answer::from)
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        get_calibration_sum(input).map(Answer::from)
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(input.clone())
+    }
+
+    /// `size` lines
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_calibration_document(size, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_get_calibration_value() {
+        assert_eq!(get_calibration_value("1abc2"), Some(12));
+        assert_eq!(get_calibration_value("pqr3stu8vwx"), Some(38));
+        assert_eq!(get_calibration_value("a1b2c3d4e5f"), Some(15));
+        assert_eq!(get_calibration_value("treb7uchet"), Some(77));
+        assert_eq!(get_calibration_value("trebuchet"), None);
+        assert_eq!(get_calibration_value("two1nine"), Some(29));
+        assert_eq!(get_calibration_value("eightwothree"), Some(83));
+        assert_eq!(get_calibration_value("abcone2threexyz"), Some(13));
+        assert_eq!(get_calibration_value("xtwone3four"), Some(24));
+        assert_eq!(get_calibration_value("4nineeightseven2"), Some(42));
+        assert_eq!(get_calibration_value("zoneight234"), Some(14));
+        assert_eq!(get_calibration_value("7pqrstsixteen"), Some(76));
+        assert_eq!(get_calibration_value("three"), Some(33));
+        assert_eq!(
+            get_calibration_value("seven8sevenptdlvvgssixvjvzpvsp7fivefourtwoned"),
+            Some(71)
+        );
+    }
+
+    #[test]
+    fn test_get_numeric_calibration_value() {
+        assert_eq!(get_numeric_calibration_value("1abc2"), Some(12));
+        assert_eq!(get_numeric_calibration_value("treb7uchet"), Some(77));
+        assert_eq!(get_numeric_calibration_value("two1nine"), Some(11));
+        assert_eq!(get_numeric_calibration_value("eightwothree"), None);
+    }
+
+    #[test]
+    fn test_get_calibration_sum() {
+        assert_eq!(get_calibration_sum(&read_input("example.txt")), Ok(281));
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day1::parse(&read_input("example.txt")).unwrap();
+        // "eightwothree" only has spelled out digits
+        assert!(Day1::part_1(&input).is_err());
+        assert_eq!(Day1::part_2(&input), Ok(Answer::from(281u32)));
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day1>(100);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day1>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day1::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day1>(generated.as_bytes()).is_some());
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(get_calibration_value("é1twoü"), Some(12));
+        assert_eq!(get_numeric_calibration_value("ü"), None);
+    }
+}
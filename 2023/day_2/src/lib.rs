@@ -0,0 +1,344 @@
+use std::fmt;
+
+use aoc_common::{
+    checked::{CheckedIterator, OrOverflow},
+    generate::{InputRng, Rng, SliceRandom},
+    parse::{parse_lines, ErrorKind, Field, Pattern},
+    Answer, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    BadHeader(String),
+    BadId(String),
+    BadDraw(String),
+    BadAmount(String),
+    UnknownColor(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::BadHeader(token)
+            | Self::BadId(token)
+            | Self::BadDraw(token)
+            | Self::BadAmount(token)
+            | Self::UnknownColor(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::BadHeader(_) => "a header like \"Game 1: \"",
+            Self::BadId(_) => "a game number",
+            Self::BadDraw(_) => "cubes like \"3 blue\"",
+            Self::BadAmount(_) => "an amount of cubes",
+            Self::UnknownColor(_) => "red, green or blue",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+#[derive(PartialEq, Eq, Debug)]
+struct CubeStats {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+impl CubeStats {
+    fn power(&self) -> Option<usize> {
+        [self.red, self.green, self.blue]
+            .into_iter()
+            .checked_product()
+    }
+    fn parse_stats_line(line: Field) -> Result<Self, ParseError> {
+        // 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        let cubes_pattern = Pattern::new("{} {}");
+        for cubes in line.split("; ").flat_map(|draw| draw.split(", ")) {
+            let [amount, color] = cubes_pattern.fields(cubes, ParseErrorKind::BadDraw)?;
+            let amount: usize = amount.parse(ParseErrorKind::BadAmount)?;
+            match color.text {
+                "red" => red = red.max(amount),
+                "green" => green = green.max(amount),
+                "blue" => blue = blue.max(amount),
+                _ => return Err(color.error(ParseErrorKind::UnknownColor)),
+            }
+        }
+
+        Ok(CubeStats { red, green, blue })
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Game {
+    id: usize,
+    cube_stats: CubeStats,
+}
+
+impl Game {
+    fn new(id: usize, cube_stats: CubeStats) -> Self {
+        Self { id, cube_stats }
+    }
+    fn parse_game_line(line: &str) -> Result<Self, ParseError> {
+        let [id, data] =
+            Pattern::new("Game {}: {}").fields(Field::new(line), ParseErrorKind::BadHeader)?;
+        let id = id.parse(ParseErrorKind::BadId)?;
+        let cube_stats = CubeStats::parse_stats_line(data)?;
+        Ok(Self::new(id, cube_stats))
+    }
+}
+
+/// Only the most cubes of each color shown are kept, so they're written as a single draw.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CubeStats { red, green, blue } = self.cube_stats;
+        write!(f, "Game {}: {red} red, {green} green, {blue} blue", self.id)
+    }
+}
+
+fn is_game_valid(game: &Game, stats: &CubeStats) -> bool {
+    game.cube_stats.red <= stats.red
+        && game.cube_stats.green <= stats.green
+        && game.cube_stats.blue <= stats.blue
+}
+
+fn parse_games(text: &str) -> Result<Vec<Game>, ParseError> {
+    parse_lines(text, Game::parse_game_line)
+}
+
+fn sum_valid_ids(games: &[Game], valid_stats: &CubeStats) -> Result<usize, SolveError> {
+    games
+        .iter()
+        .filter(|g| is_game_valid(g, valid_stats))
+        .map(|g| g.id)
+        .checked_sum()
+        .or_overflow("the sum of the game ids")
+}
+
+fn sum_powers(games: &[Game]) -> Result<usize, SolveError> {
+    games.iter().try_fold(0usize, |sum, g| {
+        let power = g.cube_stats.power().or_overflow("a game's power")?;
+        sum.checked_add(power).or_overflow("the sum of the powers")
+    })
+}
+
+/// Games of a few draws each, some of which need more cubes than part 1's bag has.
+fn generate_games(games: usize, rng: &mut InputRng) -> String {
+    let mut log = String::new();
+    for id in 1..=games {
+        let draws: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                colors[..rng.gen_range(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        log.push_str(&format!("Game {id}: {}\n", draws.join("; ")));
+    }
+    log
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        parse_games(input)
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        sum_valid_ids(
+            input,
+            &CubeStats {
+                red: 12,
+                green: 13,
+                blue: 14,
+            },
+        )
+        .map(Answer::from)
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        sum_powers(input).map(Answer::from)
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(input.iter().map(|game| format!("{game}\n")).collect())
+    }
+
+    /// `size` games
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_games(size, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input, Answer, Diagnostic,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_parse_cube_stats() {
+        assert_eq!(
+            CubeStats::parse_stats_line(Field::new(
+                "3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+            ))
+            .unwrap(),
+            CubeStats {
+                red: 4,
+                green: 2,
+                blue: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_game() {
+        assert_eq!(
+            Game::parse_game_line(
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+            )
+            .unwrap(),
+            Game::new(
+                3,
+                CubeStats {
+                    red: 20,
+                    green: 13,
+                    blue: 6
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_game_valid() {
+        assert!(is_game_valid(
+            &Game::parse_game_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")
+                .unwrap(),
+            &CubeStats {
+                red: 12,
+                green: 13,
+                blue: 14
+            }
+        ));
+        assert!(!is_game_valid(
+            &Game::parse_game_line(
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+            )
+            .unwrap(),
+            &CubeStats {
+                red: 12,
+                green: 13,
+                blue: 14
+            }
+        ));
+    }
+
+    #[test]
+    fn test_sum_valid_ids() {
+        assert_eq!(
+            sum_valid_ids(
+                &parse_games(&read_input("example.txt")).unwrap(),
+                &CubeStats {
+                    red: 12,
+                    green: 13,
+                    blue: 14
+                }
+            ),
+            Ok(8)
+        )
+    }
+
+    #[test]
+    fn test_sum_powers() {
+        assert_eq!(
+            sum_powers(&parse_games(&read_input("example.txt")).unwrap()),
+            Ok(2286)
+        );
+        let huge = parse_games("Game 1: 4294967296 red, 4294967296 green, 1 blue").unwrap();
+        assert_eq!(
+            sum_powers(&huge),
+            Err(SolveError::Overflow("a game's power".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day2::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day2::part_1(&input), Ok(Answer::from(8usize)));
+        assert_eq!(Day2::part_2(&input), Ok(Answer::from(2286usize)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Game::parse_game_line("Game 1 3 blue"),
+            Err(
+                ParseError::new(ParseErrorKind::BadHeader("Game 1 3 blue".to_string()))
+                    .with_span(0..13)
+            )
+        );
+        assert_eq!(
+            Game::parse_game_line("Game one: 3 blue"),
+            Err(ParseError::new(ParseErrorKind::BadId("one".to_string())).with_span(5..8))
+        );
+        assert_eq!(
+            Game::parse_game_line("Game 1: 3 blue, 4 red, 1 green, 2 purple"),
+            Err(
+                ParseError::new(ParseErrorKind::UnknownColor("purple".to_string()))
+                    .with_span(34..40)
+            )
+        );
+        assert_eq!(
+            parse_games("Game 1: 3 blue\nGame 2: 3blue"),
+            Err(ParseError {
+                line: 2,
+                span: Some(8..13),
+                kind: ParseErrorKind::BadDraw("3blue".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "Game 1: 3 blue\nGame 2: 3 blue, 4 red, 1 green, 2 purple";
+        let err = parse_games(input).unwrap_err();
+        assert_eq!(
+            Diagnostic::new("input.txt", input, &err).to_string(),
+            "error: expected red, green or blue, found \"purple\"\n \
+             --> input.txt:2:35\n  \
+             |\n\
+             2 | Game 2: 3 blue, 4 red, 1 green, 2 purple\n  \
+             |                                   ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day2>(100);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day2>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day2::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day2>(generated.as_bytes()).is_some());
+    }
+}
@@ -0,0 +1,503 @@
+use std::fmt;
+
+use aoc_common::{
+    checked::{CheckedIterator, OrOverflow},
+    generate::{InputRng, Rng},
+    parse::ErrorKind,
+    visualize::{Svg, Visualization},
+    Answer, Grid, Position, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    NumberTooLarge(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::NumberTooLarge(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::NumberTooLarge(_) => "a part number that fits in 32 bits",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+/// A number drawn on the schematic, starting at `start` and running right.
+#[derive(PartialEq, Eq, Debug)]
+struct Number {
+    start: Position,
+    value: u32,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Schematic {
+    /// The schematic as drawn, with '.' for empty space
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+    /// Which of the numbers each digit belongs to
+    digits: Grid<Option<usize>>,
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+impl Schematic {
+    fn parse(input: &PuzzleInput) -> Result<Self, ParseError> {
+        // Rows are padded with spaces, and trailing whitespace is trimmed from the input,
+        // so whitespace can't be a symbol anywhere else either
+        let drawn = input.grid();
+        let grid = drawn.map(|&c| if c.is_whitespace() { '.' } else { c });
+        let mut numbers = vec![];
+        let mut digits = Grid::new(grid.width(), grid.height(), None);
+        for (y, (row, drawn_row)) in grid.rows().zip(drawn.rows()).enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                if !row[x].is_ascii_digit() {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < row.len() && row[x].is_ascii_digit() {
+                    digits[Position(x as i32, y as i32)] = Some(numbers.len());
+                    x += 1;
+                }
+                // The run is only digits, so parsing can only fail on overflow
+                let run: String = row[start..x].iter().collect();
+                let value = run.parse::<u32>().map_err(|_| {
+                    // Symbols can be any character, so the columns before the number aren't bytes.
+                    // The number itself is ASCII digits.
+                    let offset: usize = drawn_row[..start].iter().map(|c| c.len_utf8()).sum();
+                    ParseError {
+                        line: y + 1,
+                        span: Some(offset..offset + run.len()),
+                        kind: ParseErrorKind::NumberTooLarge(run.clone()),
+                    }
+                })?;
+                numbers.push(Number {
+                    start: Position(start as i32, y as i32),
+                    value,
+                });
+            }
+        }
+
+        Ok(Schematic {
+            grid,
+            numbers,
+            digits,
+        })
+    }
+
+    fn is_next_to_symbol(&self, pos: Position) -> bool {
+        self.grid.neighbours_8(pos).any(|p| is_symbol(self.grid[p]))
+    }
+
+    /// Whether each of the numbers is a part number, which it is when any of its digits is next to a symbol.
+    fn part_numbers(&self) -> Vec<bool> {
+        let mut next_to_symbol = vec![false; self.numbers.len()];
+        for (pos, &number) in self.digits.iter() {
+            if let Some(number) = number {
+                next_to_symbol[number] |= self.is_next_to_symbol(pos);
+            }
+        }
+        next_to_symbol
+    }
+
+    fn sum_numbers_next_to_symbols(&self) -> Result<u64, SolveError> {
+        self.numbers
+            .iter()
+            .zip(self.part_numbers())
+            .filter(|&(_, next_to_symbol)| next_to_symbol)
+            .map(|(number, _)| u64::from(number.value))
+            .checked_sum()
+            .or_overflow("the sum of the part numbers")
+    }
+
+    fn get_numbers_around_point(&self, pos: Position) -> Vec<u32> {
+        let mut around: Vec<usize> = self
+            .digits
+            .neighbours_8(pos)
+            .filter_map(|p| self.digits[p])
+            .collect();
+        around.sort();
+        around.dedup();
+        around.iter().map(|&n| self.numbers[n].value).collect()
+    }
+
+    /// The `*`s next to exactly two numbers, with the two numbers.
+    fn gears(&self) -> impl Iterator<Item = (Position, Vec<u32>)> + '_ {
+        self.grid
+            .iter()
+            .filter(|&(_pos, &symbol)| symbol == '*')
+            .map(|(pos, _symbol)| (pos, self.get_numbers_around_point(pos)))
+            .filter(|(_pos, numbers)| numbers.len() == 2)
+    }
+
+    /// What each cell is, as far as the parts are concerned.
+    fn cells(&self) -> Grid<Cell> {
+        let part_numbers = self.part_numbers();
+        let mut cells = self.grid.map(|&c| {
+            if c == '.' {
+                Cell::Empty
+            } else if c.is_ascii_digit() {
+                Cell::Digit
+            } else {
+                Cell::Symbol
+            }
+        });
+        for (pos, &number) in self.digits.iter() {
+            if number.is_some_and(|n| part_numbers[n]) {
+                cells[pos] = Cell::PartDigit;
+            }
+        }
+        for (pos, _) in self.gears() {
+            cells[pos] = Cell::Gear;
+        }
+        cells
+    }
+
+    /// The schematic with a line under each row marking the digits of part numbers with `^` and gears with `G`.
+    fn annotate(&self) -> String {
+        let cells = self.cells();
+        let mut text = "^ = part number, G = gear\n".to_string();
+        for (row, marks) in self.grid.rows().zip(cells.rows()) {
+            text.extend(row);
+            text.push('\n');
+            let marks: String = marks
+                .iter()
+                .map(|cell| match cell {
+                    Cell::PartDigit => '^',
+                    Cell::Gear => 'G',
+                    _ => ' ',
+                })
+                .collect();
+            text.push_str(marks.trim_end());
+            text.push('\n');
+        }
+        text
+    }
+
+    /// The schematic drawn cell by cell, with part numbers and gears picked out in colour.
+    fn draw(&self) -> Svg {
+        const WIDTH: f64 = 10.0;
+        const HEIGHT: f64 = 16.0;
+        let mut svg = Svg::new(
+            self.grid.width() as f64 * WIDTH,
+            self.grid.height() as f64 * HEIGHT,
+        );
+        svg.rect(
+            0.0,
+            0.0,
+            self.grid.width() as f64 * WIDTH,
+            self.grid.height() as f64 * HEIGHT,
+            "white",
+        );
+        for (pos, cell) in self.cells().iter() {
+            let Position(x, y) = pos;
+            let (x, y) = (f64::from(x) * WIDTH, f64::from(y) * HEIGHT);
+            let background = match cell {
+                Cell::Empty => continue,
+                Cell::Digit => "#dddddd",
+                Cell::PartDigit => "#8fd18f",
+                Cell::Symbol => "#f5c26b",
+                Cell::Gear => "#f08080",
+            };
+            svg.rect(x, y, WIDTH, HEIGHT, background);
+            svg.text(
+                x + 2.0,
+                y + 12.0,
+                13.0,
+                "black",
+                &self.grid[pos].to_string(),
+            );
+        }
+        svg
+    }
+}
+
+/// What's drawn in one cell of a schematic.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Cell {
+    Empty,
+    /// A digit of a number that isn't next to any symbol
+    Digit,
+    PartDigit,
+    Symbol,
+    Gear,
+}
+
+/// Draws the schematic back the way it was, with any whitespace turned into dots.
+impl fmt::Display for Schematic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn compute_gear_factors(schematic: &Schematic) -> Result<u64, SolveError> {
+    schematic
+        .gears()
+        // Two 32 bit numbers always multiply into 64 bits
+        .map(|(_pos, v)| u64::from(v[0]) * u64::from(v[1]))
+        .checked_sum()
+        .or_overflow("the sum of the gear ratios")
+}
+
+/// A square schematic of part numbers up to 3 digits and symbols, with plenty of gears among them.
+fn generate_schematic(width: usize, rng: &mut InputRng) -> String {
+    const SYMBOLS: &[u8] = b"***#+$/@=%&-";
+    let mut schematic = String::new();
+    for _ in 0..width {
+        let mut row = String::new();
+        while row.len() < width {
+            let digits = rng.gen_range(1..=3u32);
+            match rng.gen_range(0..10) {
+                // A number needs a non-digit after it, so it isn't merged with the next one
+                0 | 1 if row.len() + (digits as usize) < width => {
+                    row.push_str(
+                        &rng.gen_range(10u32.pow(digits - 1)..10u32.pow(digits))
+                            .to_string(),
+                    );
+                    row.push('.');
+                }
+                2 => row.push(SYMBOLS[rng.gen_range(0..SYMBOLS.len())] as char),
+                _ => row.push('.'),
+            }
+        }
+        schematic.push_str(&row);
+        schematic.push('\n');
+    }
+    schematic
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        Schematic::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        input.sum_numbers_next_to_symbols().map(Answer::from)
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        compute_gear_factors(input).map(Answer::from)
+    }
+
+    /// A `size` by `size` schematic
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_schematic(size, rng))
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(input.to_string())
+    }
+
+    fn visualize(input: &Self::Input) -> Vec<Visualization> {
+        vec![
+            Visualization::text(input.annotate()),
+            Visualization::svg(&input.draw()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input, Answer,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_parse_schematic() {
+        let schematic = Schematic::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(
+            schematic
+                .numbers
+                .iter()
+                .map(|n| (n.start, n.value))
+                .collect::<Vec<_>>(),
+            vec![
+                (Position(0, 0), 467),
+                (Position(5, 0), 114),
+                (Position(2, 2), 35),
+                (Position(6, 2), 633),
+                (Position(0, 4), 617),
+                (Position(7, 5), 58),
+                (Position(2, 6), 592),
+                (Position(6, 7), 755),
+                (Position(1, 9), 664),
+                (Position(5, 9), 598),
+            ]
+        );
+        assert_eq!(
+            schematic
+                .grid
+                .iter()
+                .filter(|&(_, &c)| is_symbol(c))
+                .map(|(pos, &c)| (pos, c))
+                .collect::<Vec<_>>(),
+            vec![
+                (Position(3, 1), '*'),
+                (Position(6, 3), '#'),
+                (Position(3, 4), '*'),
+                (Position(5, 5), '+'),
+                (Position(3, 8), '$'),
+                (Position(5, 8), '*')
+            ]
+        );
+
+        let digits: Vec<(Position, usize)> = schematic
+            .digits
+            .iter()
+            .filter_map(|(pos, &number)| Some((pos, number?)))
+            .collect();
+        assert_eq!(digits.len(), 28);
+        assert_eq!(
+            &digits[..6],
+            [
+                (Position(0, 0), 0),
+                (Position(1, 0), 0),
+                (Position(2, 0), 0),
+                (Position(5, 0), 1),
+                (Position(6, 0), 1),
+                (Position(7, 0), 1),
+            ]
+        );
+        assert_eq!(schematic.digits[Position(8, 9)], None);
+    }
+
+    #[test]
+    fn test_is_next_to_symbol() {
+        let schematic = Schematic::parse(&PuzzleInput::new("!..\n...")).unwrap();
+        assert!(!schematic.is_next_to_symbol(Position(0, 0)));
+        assert!(schematic.is_next_to_symbol(Position(0, 1)));
+        assert!(schematic.is_next_to_symbol(Position(1, 1)));
+        assert!(schematic.is_next_to_symbol(Position(1, 0)));
+        assert!(schematic.is_next_to_symbol(Position(-1, 0)));
+        assert!(!schematic.is_next_to_symbol(Position(2, 0)));
+    }
+
+    #[test]
+    fn test_sum_nums_next_to_symbols() {
+        let schematic = Schematic::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(schematic.sum_numbers_next_to_symbols(), Ok(4361));
+    }
+
+    #[test]
+    fn test_sum_around_point() {
+        let schematic = Schematic::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(
+            schematic.get_numbers_around_point(Position(3, 1)),
+            vec![467, 35]
+        );
+    }
+
+    #[test]
+    fn test_compute_gear_factors() {
+        let schematic = Schematic::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(compute_gear_factors(&schematic), Ok(467835));
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day3::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day3::part_1(&input), Ok(Answer::from(4361u32)));
+        assert_eq!(Day3::part_2(&input), Ok(Answer::from(467835u32)));
+    }
+
+    #[test]
+    fn test_annotate() {
+        let input = Day3::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(
+            input.annotate(),
+            "^ = part number, G = gear\n\
+             467..114..\n\
+             ^^^\n\
+             ...*......\n\
+             \x20  G\n\
+             ..35..633.\n\
+             \x20 ^^  ^^^\n\
+             ......#...\n\
+             \n\
+             617*......\n\
+             ^^^\n\
+             .....+.58.\n\
+             \n\
+             ..592.....\n\
+             \x20 ^^^\n\
+             ......755.\n\
+             \x20     ^^^\n\
+             ...$.*....\n\
+             \x20    G\n\
+             .664.598..\n\
+             \x20^^^ ^^^\n"
+        );
+    }
+
+    #[test]
+    fn test_draw() {
+        let input = Day3::parse(&read_input("example.txt")).unwrap();
+        let svg = input.draw().to_string();
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="160""#)
+        );
+        let count = |fill: &str| svg.matches(&format!(r#"fill="{fill}"/>"#)).count();
+        // 114 and 58 aren't part numbers
+        assert_eq!(count("#dddddd"), 5);
+        assert_eq!(count("#8fd18f"), 8 * 3 - 1);
+        assert_eq!(count("#f08080"), 2);
+        // The `*` next to a single number isn't a gear
+        assert_eq!(count("#f5c26b"), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Schematic::parse(&PuzzleInput::new("...*..\n.99999999999.")).err(),
+            Some(ParseError {
+                line: 2,
+                span: Some(1..12),
+                kind: ParseErrorKind::NumberTooLarge("99999999999".to_string())
+            })
+        );
+        let input = PuzzleInput::new("#é123456789012");
+        let err = Schematic::parse(&input).unwrap_err();
+        assert_eq!(err.span, Some(3..15));
+        assert_eq!(&input[3..15], "123456789012");
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day3>(100);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day3>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day3::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day3>(generated.as_bytes()).is_some());
+
+        // Leading zeros are kept, since they're what puts the number next to the symbol
+        let schematic = Schematic::parse(&PuzzleInput::new("..007*\n#.....")).unwrap();
+        assert_eq!(schematic.to_string(), "..007*\n#.....\n");
+        assert_eq!(schematic.sum_numbers_next_to_symbols(), Ok(7));
+        assert!(check_round_trip::<Day3>(b"..007*\n#.....").is_some());
+        // Whitespace is empty space, like '.'
+        assert!(check_round_trip::<Day3>(b"1*\r.\n\t2").is_some());
+    }
+}
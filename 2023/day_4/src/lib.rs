@@ -0,0 +1,369 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    checked::{CheckedIterator, OrOverflow},
+    generate::{InputRng, Rng, SliceRandom},
+    parse::{parse_lines, ErrorKind, Field, Pattern},
+    Answer, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    BadHeader(String),
+    MissingSeparator(String),
+    BadNumber(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::BadHeader(token) | Self::MissingSeparator(token) | Self::BadNumber(token) => {
+                token
+            }
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::BadHeader(_) => "a header like \"Card 1: \"",
+            Self::MissingSeparator(_) => "numbers separated by \" | \"",
+            Self::BadNumber(_) => "a number",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+fn parse_numbers(field: Field) -> Result<HashSet<u32>, ParseError> {
+    Ok(field
+        .numbers(ParseErrorKind::BadNumber)?
+        .into_iter()
+        .collect())
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    your_numbers: HashSet<u32>,
+}
+
+impl Card {
+    ///
+    /// Card 1: 10 20 30 40 | 50 60 70 80
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let [_id, numbers] =
+            Pattern::new("Card {}: {}").fields(Field::new(line), ParseErrorKind::BadHeader)?;
+        let [winnings, yours] =
+            Pattern::new("{} | {}").fields(numbers, ParseErrorKind::MissingSeparator)?;
+        let your_numbers = parse_numbers(yours)?;
+        let winning_numbers = parse_numbers(winnings)?;
+        Ok(Self {
+            your_numbers,
+            winning_numbers,
+        })
+    }
+
+    fn number_overlap_count(&self) -> usize {
+        self.winning_numbers
+            .intersection(&self.your_numbers)
+            .count()
+    }
+
+    /// `None` when the card wins so many numbers its value doesn't fit in a `usize`.
+    fn value(&self) -> Option<usize> {
+        let overlap = self.number_overlap_count();
+        if overlap == 0 {
+            Some(0)
+        } else {
+            1usize.checked_shl(u32::try_from(overlap - 1).ok()?)
+        }
+    }
+}
+
+/// Counts the copies of each card in a single pass: every copy of a card wins one copy of each of the cards after it.
+/// The copies can double with every card, so they're counted rather than processed one by one.
+fn compute_card_count(cards: &[Card]) -> Result<usize, SolveError> {
+    let mut copies = vec![1usize; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        // Cards never make you copy past the end of the table
+        let won = (i + 1)..(i + 1 + card.number_overlap_count()).min(cards.len());
+        for j in won {
+            copies[j] = copies[j]
+                .checked_add(copies[i])
+                .or_overflow("a card's copies")?;
+        }
+    }
+    copies
+        .into_iter()
+        .checked_sum()
+        .or_overflow("the number of cards")
+}
+
+fn parse_cards(s: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(s, Card::parse)
+}
+
+/// Cards only know their numbers, so they're numbered by their position in the table.
+fn write_cards(cards: &[Card]) -> String {
+    let column = |numbers: &HashSet<u32>| {
+        let mut numbers: Vec<_> = numbers.iter().collect();
+        numbers.sort();
+        numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    cards
+        .iter()
+        .enumerate()
+        .map(|(i, card)| {
+            format!(
+                "Card {}: {} | {}\n",
+                i + 1,
+                column(&card.winning_numbers),
+                column(&card.your_numbers)
+            )
+        })
+        .collect()
+}
+
+fn compute_winnings(cards: &[Card]) -> Result<usize, SolveError> {
+    cards.iter().try_fold(0usize, |sum, card| {
+        let value = card.value().or_overflow("a card's value")?;
+        sum.checked_add(value)
+            .or_overflow("the sum of the card values")
+    })
+}
+
+/// Cards with 10 winning numbers and 25 of yours, like the real ones.
+///
+/// How many numbers a card wins is picked first, and kept low enough on average that the copies part 2 makes
+/// stay countable, and never so high that a card wins copies of cards past the end of the table.
+fn generate_cards(cards: usize, rng: &mut InputRng) -> String {
+    let mut table = String::new();
+    for card in 1..=cards {
+        let wins = if rng.gen_bool(0.6) {
+            0
+        } else {
+            rng.gen_range(1..=3)
+        }
+        .min(cards - card);
+
+        let mut numbers: Vec<u32> = (1..100).collect();
+        numbers.shuffle(rng);
+        let (winning, others) = numbers.split_at(10);
+        let mut yours: Vec<u32> = winning[..wins]
+            .iter()
+            .chain(&others[..25 - wins])
+            .copied()
+            .collect();
+        yours.shuffle(rng);
+
+        let column = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        table.push_str(&format!(
+            "Card {card:>3}: {} | {}\n",
+            column(winning),
+            column(&yours)
+        ));
+    }
+    table
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        parse_cards(input)
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        compute_winnings(input).map(Answer::from)
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        compute_card_count(input).map(Answer::from)
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(write_cards(input))
+    }
+
+    /// `size` cards
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_cards(size, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input, Answer,
+    };
+    use proptest::{
+        collection::{hash_set, vec},
+        prelude::*,
+    };
+
+    use super::*;
+
+    /// Processes every copy of every card one at a time, the way the puzzle describes it.
+    fn compute_card_count_by_copying(cards: &[Card]) -> usize {
+        let mut cards_to_process: VecDeque<usize> = (0..cards.len()).collect();
+        let mut cards_processed = 0;
+        while let Some(card_index) = cards_to_process.pop_front() {
+            let overlaps = cards[card_index].number_overlap_count();
+            cards_to_process.extend((card_index + 1)..(card_index + 1 + overlaps).min(cards.len()));
+            cards_processed += 1;
+        }
+        cards_processed
+    }
+
+    fn card() -> impl Strategy<Value = Card> {
+        (hash_set(1..30u32, 0..6), hash_set(1..30u32, 0..8)).prop_map(
+            |(winning_numbers, your_numbers)| Card {
+                winning_numbers,
+                your_numbers,
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_compute_card_count_matches_copying(cards in vec(card(), 0..12)) {
+            prop_assert_eq!(compute_card_count(&cards), Ok(compute_card_count_by_copying(&cards)));
+        }
+    }
+
+    #[test]
+    fn test_parse_card() {
+        let card = Card::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(
+            card.winning_numbers,
+            [41, 48, 83, 86, 17].into_iter().collect()
+        );
+        assert_eq!(
+            card.your_numbers,
+            [83, 86, 6, 31, 17, 9, 48, 53].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn test_overlap_count() {
+        let card = Card::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(card.number_overlap_count(), 4);
+    }
+
+    #[test]
+    fn test_card_value() {
+        assert_eq!(
+            Card::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+                .unwrap()
+                .value(),
+            Some(8)
+        );
+        assert_eq!(
+            Card::parse("Card 2: 1 2 3 | 4 5 6").unwrap().value(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_compute_winnings() {
+        assert_eq!(
+            compute_winnings(&parse_cards(&read_input("example.txt")).unwrap()),
+            Ok(13)
+        );
+        let numbers: Vec<String> = (1..=70).map(|n| n.to_string()).collect();
+        let numbers = numbers.join(" ");
+        let card = format!("Card 1: {numbers} | {numbers}\n");
+        assert_eq!(
+            compute_winnings(&parse_cards(&card).unwrap()),
+            Err(SolveError::Overflow("a card's value".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_compute_card_count() {
+        assert_eq!(
+            compute_card_count(&parse_cards(&read_input("example.txt")).unwrap()),
+            Ok(30)
+        );
+        // Every card wins a copy of the next ten, so the copies grow exponentially
+        let cards: String = (1..=100)
+            .map(|i| format!("Card {i}: 1 2 3 4 5 6 7 8 9 10 | 1 2 3 4 5 6 7 8 9 10\n"))
+            .collect();
+        assert_eq!(
+            compute_card_count(&parse_cards(&cards).unwrap()),
+            Err(SolveError::Overflow("a card's copies".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day4::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day4::part_1(&input), Ok(Answer::from(13usize)));
+        assert_eq!(Day4::part_2(&input), Ok(Answer::from(30usize)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Card::parse("Card 1 41 48 | 83 86").err(),
+            Some(
+                ParseError::new(ParseErrorKind::BadHeader(
+                    "Card 1 41 48 | 83 86".to_string()
+                ))
+                .with_span(0..20)
+            )
+        );
+        assert_eq!(
+            Card::parse("Card 1: 41 48 83 86").err(),
+            Some(
+                ParseError::new(ParseErrorKind::MissingSeparator("41 48 83 86".to_string()))
+                    .with_span(8..19)
+            )
+        );
+        assert_eq!(
+            parse_cards("Card 1: 41 48 | 83 86\nCard 2: 13 3x | 61 30").err(),
+            Some(ParseError {
+                line: 2,
+                span: Some(11..13),
+                kind: ParseErrorKind::BadNumber("3x".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day4>(100);
+
+        let cards = parse_cards(&Day4::generate(100, &mut rng(1)).unwrap()).unwrap();
+        for (i, card) in cards.iter().enumerate() {
+            assert!(card.number_overlap_count() <= 3.min(cards.len() - 1 - i));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day4>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day4::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day4>(generated.as_bytes()).is_some());
+
+        // No winning numbers at all
+        assert!(check_round_trip::<Day4>(b"Card 1:  | 5").is_some());
+    }
+}
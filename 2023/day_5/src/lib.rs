@@ -0,0 +1,727 @@
+use aoc_common::{
+    checked::OrOverflow,
+    generate::{InputRng, Rng, SliceRandom},
+    parse::{ErrorKind, Field, Pattern},
+    visualize::{Svg, Visualization},
+    PuzzleInput, Solution, SolveError, SolveResult,
+};
+use std::{collections::HashMap, iter, ops::Range};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    MissingSeeds(String),
+    MissingHeader(String),
+    BadEntry(String),
+    BadNumber(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::MissingSeeds(token)
+            | Self::MissingHeader(token)
+            | Self::BadEntry(token)
+            | Self::BadNumber(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::MissingSeeds(_) => "a seed list like \"seeds: 79 14\"",
+            Self::MissingHeader(_) => "a map header like \"seed-to-soil map:\"",
+            Self::BadEntry(_) => "a destination start, source start and range length",
+            Self::BadNumber(_) => "a number",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+#[derive(PartialEq, Eq, Debug)]
+struct RangeTransformation {
+    src: usize,
+    dst: usize,
+    length: usize,
+}
+
+impl RangeTransformation {
+    fn new(src: usize, dst: usize, length: usize) -> Self {
+        Self { src, dst, length }
+    }
+    /// Whether the ends of both ranges fit in a `usize`, which the rest of the arithmetic relies on.
+    fn fits(&self) -> bool {
+        self.src.checked_add(self.length).is_some() && self.dst.checked_add(self.length).is_some()
+    }
+    fn contains(&self, value: usize) -> bool {
+        self.src <= value && value < self.src + self.length
+    }
+    fn transform(&self, value: usize) -> Option<usize> {
+        if self.contains(value) {
+            Some(self.dst + (value - self.src))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Almanac {
+    mappings: HashMap<String, Vec<RangeTransformation>>,
+}
+
+impl Almanac {
+    fn new() -> Self {
+        Self {
+            mappings: HashMap::default(),
+        }
+    }
+
+    fn add_entry(
+        &mut self,
+        category: &str,
+        dst_range_start: usize,
+        src_range_start: usize,
+        range_length: usize,
+    ) {
+        if !self.mappings.contains_key(category) {
+            self.mappings.insert(category.to_string(), vec![]);
+        }
+
+        let vec = self
+            .mappings
+            .get_mut(category)
+            .expect("Should've been inserted if doesn't exist!");
+        vec.push(RangeTransformation::new(
+            src_range_start,
+            dst_range_start,
+            range_length,
+        ));
+    }
+
+    fn add_entry_line(&mut self, category: &str, line: Field) -> Result<(), ParseError> {
+        let parts: Vec<_> = line.words().collect();
+        let [dst_range_start, src_range_start, range_length] = parts[..] else {
+            return Err(line.error(ParseErrorKind::BadEntry));
+        };
+        self.add_entry(
+            category,
+            dst_range_start.parse(ParseErrorKind::BadNumber)?,
+            src_range_start.parse(ParseErrorKind::BadNumber)?,
+            range_length.parse(ParseErrorKind::BadNumber)?,
+        );
+        Ok(())
+    }
+
+    /// Makes sure no entry's ranges go past the biggest `usize`, so transforming values can't overflow.
+    fn check_ranges(&self) -> Result<(), SolveError> {
+        if self
+            .mappings
+            .values()
+            .flatten()
+            .all(RangeTransformation::fits)
+        {
+            Ok(())
+        } else {
+            Err(SolveError::Overflow("the end of a map's range".to_string()))
+        }
+    }
+
+    /// A map without entries keeps every value, just like values none of its entries contain.
+    fn entries(&self, transformation: &str) -> &[RangeTransformation] {
+        self.mappings.get(transformation).map_or(&[], Vec::as_slice)
+    }
+
+    fn apply_transformation(&self, value: usize, transformation: &str) -> usize {
+        self.entries(transformation)
+            .iter()
+            .filter_map(|t| t.transform(value))
+            .next()
+            .unwrap_or(value)
+    }
+
+    fn compute_value(&self, start_value: usize, transformations: &[&str]) -> usize {
+        let mut result = start_value;
+        for &t in transformations {
+            result = self.apply_transformation(result, t);
+        }
+        result
+    }
+
+    /// Transforms whole ranges of values at once, splitting them wherever a different entry applies.
+    /// Like for single values, the first entry containing a value wins and values no entry contains are kept.
+    fn apply_transformation_to_ranges(
+        &self,
+        ranges: Vec<Range<usize>>,
+        transformation: &str,
+    ) -> Vec<Range<usize>> {
+        let mut transformed = vec![];
+        let mut untouched = ranges;
+        for t in self.entries(transformation) {
+            let mut rest = vec![];
+            for range in untouched {
+                let start = range.start.max(t.src);
+                let end = range.end.min(t.src + t.length);
+                if start >= end {
+                    rest.push(range);
+                    continue;
+                }
+                transformed.push(t.dst + (start - t.src)..t.dst + (end - t.src));
+                if range.start < start {
+                    rest.push(range.start..start);
+                }
+                if end < range.end {
+                    rest.push(end..range.end);
+                }
+            }
+            untouched = rest;
+        }
+        transformed.extend(untouched);
+        transformed
+    }
+
+    fn compute_ranges(
+        &self,
+        start_ranges: Vec<Range<usize>>,
+        transformations: &[&str],
+    ) -> Vec<Range<usize>> {
+        let mut result = start_ranges;
+        for &t in transformations {
+            result = self.apply_transformation_to_ranges(result, t);
+        }
+        result
+    }
+}
+
+fn parse_almanac(input: &PuzzleInput) -> Result<(Almanac, Vec<usize>), ParseError> {
+    // The seeds come first, and then the maps, each in its own section starting with its header
+    let mut lines = input.sections().into_iter().flat_map(|section| {
+        section
+            .lines()
+            .enumerate()
+            .map(|(i, (line_number, line))| (i == 0, line_number, line))
+    });
+
+    let (_, seeds_line_number, first_line) = lines.next().unwrap_or((true, 1, ""));
+    let seeds = Pattern::new("seeds: {}")
+        .fields(Field::new(first_line), ParseErrorKind::MissingSeeds)
+        .and_then(|[seed_list]| seed_list.numbers(ParseErrorKind::BadNumber))
+        .map_err(|err| err.in_line(seeds_line_number, first_line))?;
+
+    let header = Pattern::new("{} map:");
+    let mut almanac = Almanac::new();
+    let mut category = None;
+    for (starts_section, i, line) in lines {
+        let line_field = Field::new(line);
+        let parsed = match category {
+            _ if starts_section => header
+                .fields(line_field, ParseErrorKind::MissingHeader)
+                .map(|[name]| category = Some(name.text)),
+            Some(category) => almanac.add_entry_line(category, line_field),
+            None => Err(line_field.error(ParseErrorKind::MissingHeader)),
+        };
+        parsed.map_err(|err| err.in_line(i, line))?;
+    }
+
+    Ok((almanac, seeds))
+}
+
+/// Maps that appeared more than once are written as one, keeping their entries in order.
+fn write_almanac(almanac: &Almanac, seeds: &[usize]) -> String {
+    let seeds: Vec<String> = seeds.iter().map(|seed| seed.to_string()).collect();
+    let mut text = format!("seeds: {}\n", seeds.join(" "));
+    let mut categories: Vec<&String> = almanac.mappings.keys().collect();
+    categories.sort();
+    for category in categories {
+        text.push_str(&format!("\n{category} map:\n"));
+        for t in &almanac.mappings[category] {
+            text.push_str(&format!("{} {} {}\n", t.dst, t.src, t.length));
+        }
+    }
+    text
+}
+
+/// The second half of the puzzle reads the seed list as pairs of range start and length.
+fn seed_ranges(seeds: &[usize]) -> Result<Vec<Range<usize>>, SolveError> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(SolveError::Unsupported(
+            "Seeds must come in pairs!".to_string(),
+        ));
+    }
+    seeds
+        .chunks(2)
+        .map(|pair| {
+            let end = pair[0]
+                .checked_add(pair[1])
+                .or_overflow("the end of a seed range")?;
+            Ok(pair[0]..end)
+        })
+        .collect()
+}
+
+const SEED_TO_LOCATION: &[&str] = &[
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+fn compute_seed_location(almanac: &Almanac, seed: usize) -> usize {
+    almanac.compute_value(seed, SEED_TO_LOCATION)
+}
+
+fn find_lowest_seed(almanac: &Almanac, seeds: &[usize]) -> Result<usize, SolveError> {
+    almanac.check_ranges()?;
+    seeds
+        .iter()
+        .map(|&seed| compute_seed_location(almanac, seed))
+        .min()
+        .ok_or_else(no_seeds)
+}
+
+/// There are far too many seeds to follow one by one, so their ranges are followed instead.
+fn find_lowest_seed_in_ranges(almanac: &Almanac, seeds: &[usize]) -> Result<usize, SolveError> {
+    almanac.check_ranges()?;
+    let mut ranges = seed_ranges(seeds)?;
+    ranges.retain(|r| !r.is_empty());
+    almanac
+        .compute_ranges(ranges, SEED_TO_LOCATION)
+        .iter()
+        .map(|r| r.start)
+        .min()
+        .ok_or_else(no_seeds)
+}
+
+fn no_seeds() -> SolveError {
+    SolveError::Unsupported("No seeds :(".to_string())
+}
+
+/// The values in `0..max` that none of `entries` take values from, which the map keeps as they are.
+fn uncovered(entries: &[RangeTransformation], max: usize) -> Vec<Range<usize>> {
+    let mut covered: Vec<Range<usize>> = entries
+        .iter()
+        .map(|t| t.src..t.src.saturating_add(t.length))
+        .collect();
+    covered.sort_by_key(|r| r.start);
+    let mut gaps = vec![];
+    let mut from = 0;
+    for r in covered {
+        if r.start > from {
+            gaps.push(from..r.start);
+        }
+        from = from.max(r.end);
+    }
+    if from < max {
+        gaps.push(from..max);
+    }
+    gaps
+}
+
+/// Draws each map from seeds to locations as bands from the values it takes (on the left) to the ones it gives
+/// (on the right), one colour per entry and gray for the values it keeps. The seed ranges of the second half are
+/// followed through the maps in black.
+fn draw_almanac(almanac: &Almanac, seeds: &[usize]) -> Svg {
+    const LEFT: f64 = 100.0;
+    const TOP: f64 = 40.0;
+    const STAGE: f64 = 160.0;
+    const HEIGHT: f64 = 600.0;
+    const COLOURS: &[&str] = &[
+        "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    ];
+
+    let seed_ranges = seed_ranges(seeds).unwrap_or_default();
+    let max = almanac
+        .mappings
+        .values()
+        .flatten()
+        .flat_map(|t| {
+            [
+                t.src.saturating_add(t.length),
+                t.dst.saturating_add(t.length),
+            ]
+        })
+        .chain(seed_ranges.iter().map(|r| r.end))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let x = |column: usize| LEFT + column as f64 * STAGE;
+    let y = |value: usize| TOP + value as f64 / max as f64 * HEIGHT;
+
+    let (width, height) = (x(SEED_TO_LOCATION.len()) + LEFT, TOP + HEIGHT + 20.0);
+    let mut svg = Svg::new(width, height);
+    svg.rect(0.0, 0.0, width, height, "white");
+    for (stage, &map) in SEED_TO_LOCATION.iter().enumerate() {
+        let (from, to) = (x(stage), x(stage + 1));
+        let band = |src: usize, dst: usize, length: usize| {
+            [
+                (from, y(src)),
+                (from, y(src.saturating_add(length))),
+                (to, y(dst.saturating_add(length))),
+                (to, y(dst)),
+            ]
+        };
+        for gap in uncovered(almanac.entries(map), max) {
+            svg.polygon(&band(gap.start, gap.start, gap.len()), "gray", 0.15);
+        }
+        for (i, t) in almanac.entries(map).iter().enumerate() {
+            svg.polygon(
+                &band(t.src, t.dst, t.length),
+                COLOURS[i % COLOURS.len()],
+                0.5,
+            );
+        }
+    }
+
+    let categories = iter::once("seed").chain(
+        SEED_TO_LOCATION
+            .iter()
+            .map(|map| map.split_once("-to-").map_or(*map, |(_, to)| to)),
+    );
+    for (column, category) in categories.enumerate() {
+        svg.line((x(column), TOP), (x(column), TOP + HEIGHT), "black");
+        // Centred on the axis, going by the width of a monospace character
+        let label_x = x(column) - 3.9 * category.len() as f64;
+        svg.text(label_x, TOP - 12.0, 13.0, "black", category);
+    }
+    svg.text(4.0, TOP + 4.0, 11.0, "black", "0");
+    svg.text(4.0, TOP + HEIGHT, 11.0, "black", &max.to_string());
+
+    // Following ranges through maps whose ranges overflow would overflow too
+    let mut ranges = if almanac.check_ranges().is_ok() {
+        seed_ranges
+    } else {
+        vec![]
+    };
+    for column in 0..=SEED_TO_LOCATION.len() {
+        for r in &ranges {
+            let length = (y(r.end) - y(r.start)).max(1.0);
+            svg.rect(x(column) - 3.0, y(r.start), 6.0, length, "black");
+        }
+        if let Some(map) = SEED_TO_LOCATION.get(column) {
+            ranges = almanac.apply_transformation_to_ranges(ranges, map);
+        }
+    }
+    svg
+}
+
+/// An almanac over 32 bit values like the real one, with 10 huge seed ranges and `entries` ranges in each map.
+///
+/// Each map's source ranges don't overlap, but they leave gaps where values are kept as they are.
+fn generate_almanac(entries: usize, rng: &mut InputRng) -> String {
+    const VALUES: usize = 1 << 32;
+    let seeds: Vec<String> = (0..10)
+        .flat_map(|_| {
+            let length = rng.gen_range(1..(1 << 28));
+            [rng.gen_range(0..VALUES - length), length]
+        })
+        .map(|n| n.to_string())
+        .collect();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+    for category in SEED_TO_LOCATION {
+        let mut bounds: Vec<usize> = (0..=entries).map(|_| rng.gen_range(0..VALUES)).collect();
+        bounds.sort();
+        bounds.dedup();
+        let mut lines = vec![];
+        for range in bounds.windows(2) {
+            if rng.gen_bool(0.8) {
+                let length = range[1] - range[0];
+                let dst = rng.gen_range(0..=VALUES - length);
+                lines.push(format!("{dst} {} {length}", range[0]));
+            }
+        }
+        lines.shuffle(rng);
+        almanac.push_str(&format!("\n{category} map:\n"));
+        for line in lines {
+            almanac.push_str(&line);
+            almanac.push('\n');
+        }
+    }
+    almanac
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Almanac, Vec<usize>);
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        parse_almanac(input)
+    }
+
+    fn part_1((almanac, seeds): &Self::Input) -> SolveResult {
+        Ok(find_lowest_seed(almanac, seeds)?.into())
+    }
+
+    fn part_2((almanac, seeds): &Self::Input) -> SolveResult {
+        Ok(find_lowest_seed_in_ranges(almanac, seeds)?.into())
+    }
+
+    fn serialize((almanac, seeds): &Self::Input) -> Option<String> {
+        Some(write_almanac(almanac, seeds))
+    }
+
+    fn visualize((almanac, seeds): &Self::Input) -> Vec<Visualization> {
+        vec![Visualization::svg(&draw_almanac(almanac, seeds))]
+    }
+
+    /// `size` ranges in each map
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_almanac(size, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input, Answer,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test_add_entry() {
+        let mut almanac = Almanac::new();
+        almanac.add_entry("cool", 3, 5, 2);
+        assert_eq!(
+            almanac.mappings["cool"],
+            vec![RangeTransformation::new(5, 3, 2)]
+        )
+    }
+
+    #[test]
+    fn test_add_entry_line() {
+        let mut almanac = Almanac::new();
+        almanac.add_entry_line("cool", Field::new("3 5 2")).unwrap();
+        assert_eq!(
+            almanac.mappings["cool"],
+            vec![RangeTransformation::new(5, 3, 2)]
+        )
+    }
+
+    #[test]
+    fn test_parse_almanac() {
+        let (almanac, seeds) = parse_almanac(&read_input("example.txt")).unwrap();
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+        assert_eq!(seed_ranges(&seeds), Ok(vec![79..(79 + 14), 55..(55 + 13)]));
+        assert_eq!(
+            almanac.mappings["humidity-to-location"],
+            vec![
+                RangeTransformation::new(56, 60, 37),
+                RangeTransformation::new(93, 56, 4)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_compute_value() {
+        let mut almanac = Almanac::new();
+        almanac.add_entry("my-category", 0, 2, 2);
+        almanac.add_entry("my-second-category", 4, 0, 2);
+
+        // Simple
+        assert_eq!(almanac.compute_value(2, &["my-category"]), 0);
+
+        // Chain mappings
+        assert_eq!(
+            almanac.compute_value(2, &["my-category", "my-second-category"]),
+            4
+        );
+
+        // No mapping - return same value
+        assert_eq!(almanac.compute_value(4, &["my-category"]), 4);
+    }
+
+    #[test]
+    fn test_compute_ranges() {
+        let mut almanac = Almanac::new();
+        almanac.add_entry("my-category", 10, 2, 2);
+        almanac.add_entry("my-second-category", 0, 11, 5);
+
+        // Split around the entry
+        assert_eq!(
+            almanac.compute_ranges(vec![0..6, 20..22], &["my-category"]),
+            vec![10..12, 0..2, 4..6, 20..22]
+        );
+
+        // Chain mappings
+        assert_eq!(
+            almanac.compute_ranges(vec![3..5, 14..16], &["my-category", "my-second-category"]),
+            vec![0..1, 3..5, 4..5]
+        );
+    }
+
+    fn find_lowest_seed_one_by_one(almanac: &Almanac, seeds: &[usize]) -> Option<usize> {
+        seed_ranges(seeds)
+            .ok()?
+            .into_iter()
+            .flatten()
+            .map(|seed| compute_seed_location(almanac, seed))
+            .min()
+    }
+
+    proptest! {
+        #[test]
+        fn test_ranges_match_seed_by_seed(
+            entries in vec(vec((0..100usize, 0..100usize, 1..20usize), 0..4), SEED_TO_LOCATION.len()),
+            seeds in vec((0..100usize, 0..20usize), 0..4),
+        ) {
+            let mut almanac = Almanac::new();
+            for (&category, entries) in SEED_TO_LOCATION.iter().zip(&entries) {
+                for &(dst, src, length) in entries {
+                    almanac.add_entry(category, dst, src, length);
+                }
+            }
+            let seeds: Vec<usize> = seeds.into_iter().flat_map(|(start, length)| [start, length]).collect();
+            prop_assert_eq!(
+                find_lowest_seed_in_ranges(&almanac, &seeds).ok(),
+                find_lowest_seed_one_by_one(&almanac, &seeds)
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute_seed_location() {
+        let (almanac, _seeds) = parse_almanac(&read_input("example.txt")).unwrap();
+        assert_eq!(compute_seed_location(&almanac, 79), 82);
+    }
+
+    #[test]
+    fn test_find_lowest_seed() {
+        let (almanac, seeds) = parse_almanac(&read_input("example.txt")).unwrap();
+        assert_eq!(find_lowest_seed(&almanac, &seeds), Ok(35));
+    }
+
+    #[test]
+    fn test_find_lowest_seed_in_ranges() {
+        let (almanac, seeds) = parse_almanac(&read_input("example.txt")).unwrap();
+        assert_eq!(find_lowest_seed_in_ranges(&almanac, &seeds), Ok(46));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = usize::MAX;
+        let (almanac, seeds) = parse_almanac(&PuzzleInput::new(&format!(
+            "seeds: {max} 1\n\nseed-to-soil map:\n0 {max} 1"
+        )))
+        .unwrap();
+        let overflow = Err(SolveError::Overflow("the end of a map's range".to_string()));
+        assert_eq!(find_lowest_seed(&almanac, &seeds), overflow);
+        assert_eq!(find_lowest_seed_in_ranges(&almanac, &seeds), overflow);
+        assert_eq!(
+            seed_ranges(&seeds),
+            Err(SolveError::Overflow("the end of a seed range".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_range_transformation() {
+        let r = RangeTransformation::new(1, 5, 2);
+        assert!(r.contains(1));
+        assert!(r.contains(2));
+        assert!(!r.contains(3));
+        assert_eq!(r.transform(1), Some(5));
+        assert_eq!(r.transform(2), Some(6));
+        assert_eq!(r.transform(3), None);
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day5::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day5::part_1(&input), Ok(Answer::from(35usize)));
+        assert_eq!(Day5::part_2(&input), Ok(Answer::from(46usize)));
+    }
+
+    #[test]
+    fn test_uncovered() {
+        let entries = [
+            RangeTransformation::new(10, 0, 5),
+            RangeTransformation::new(2, 0, 3),
+            RangeTransformation::new(12, 0, 8),
+        ];
+        assert_eq!(uncovered(&entries, 30), vec![0..2, 5..10, 20..30]);
+        assert_eq!(uncovered(&entries, 20), vec![0..2, 5..10]);
+        assert_eq!(uncovered(&[], 7), vec![0..7]);
+    }
+
+    #[test]
+    fn test_draw_almanac() {
+        let (almanac, seeds) = Day5::parse(&read_input("example.txt")).unwrap();
+        let svg = draw_almanac(&almanac, &seeds).to_string();
+        for category in ["seed", "soil", "humidity", "location"] {
+            assert!(svg.contains(&format!(">{category}</text>")));
+        }
+        let entries = almanac.mappings.values().flatten().count();
+        assert_eq!(svg.matches(r#"fill-opacity="0.5""#).count(), entries);
+        // Both seed ranges are followed all the way through, splitting on the way
+        let seed_marks = svg.matches(r#"width="6""#).count();
+        assert!(seed_marks >= 2 * 8, "{seed_marks} seed range marks");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_almanac(&PuzzleInput::new("79 14 55 13")).err(),
+            Some(ParseError {
+                line: 1,
+                span: Some(0..11),
+                kind: ParseErrorKind::MissingSeeds("79 14 55 13".to_string())
+            })
+        );
+        assert_eq!(
+            parse_almanac(&PuzzleInput::new("seeds: 79 14\n\n50 98 2")).err(),
+            Some(ParseError {
+                line: 3,
+                span: Some(0..7),
+                kind: ParseErrorKind::MissingHeader("50 98 2".to_string())
+            })
+        );
+        assert_eq!(
+            parse_almanac(&PuzzleInput::new(
+                "seeds: 79 14\n\nseed-to-soil map:\n50 98"
+            ))
+            .err(),
+            Some(ParseError {
+                line: 4,
+                span: Some(0..5),
+                kind: ParseErrorKind::BadEntry("50 98".to_string())
+            })
+        );
+        assert_eq!(
+            parse_almanac(&PuzzleInput::new("seeds: 79 1x")).err(),
+            Some(ParseError {
+                line: 1,
+                span: Some(10..12),
+                kind: ParseErrorKind::BadNumber("1x".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let (almanac, seeds) = parse_almanac(&PuzzleInput::new(
+            &read_input("example.txt").replace('\n', "\r\n"),
+        ))
+        .unwrap();
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+        assert_eq!(almanac.mappings.len(), 7);
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day5>(100);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day5>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day5::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day5>(generated.as_bytes()).is_some());
+    }
+}
@@ -0,0 +1,369 @@
+use aoc_common::{
+    checked::{CheckedIterator, OrOverflow},
+    parse::ErrorKind,
+    Answer, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    BadHeader(String),
+    BadNumber(String),
+    NoRaces(String),
+    RaceCountMismatch(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::BadHeader(token)
+            | Self::BadNumber(token)
+            | Self::NoRaces(token)
+            | Self::RaceCountMismatch(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::BadHeader(_) => {
+                "a line like \"Time: 7 15 30\" followed by \"Distance: 9 40 200\""
+            }
+            Self::BadNumber(_) => "a whole number",
+            Self::NoRaces(_) => "at least one race",
+            Self::RaceCountMismatch(_) => "a distance for every race time",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Game(u64, u64);
+
+impl Game {
+    fn total_seconds(&self) -> u64 {
+        self.0
+    }
+    fn distance_to_beat(&self) -> u64 {
+        self.1
+    }
+}
+
+/// Whether holding the button for `held` seconds beats the record. Times and distances are 64 bits, so the
+/// distance travelled always fits in 128.
+fn wins(held: u128, total_seconds: u128, distance_to_beat: u128) -> bool {
+    held * (total_seconds - held) > distance_to_beat
+}
+
+/// Finds the possible wins by representing the question as a function of seconds pressed:
+/// distance_traveled(seconds) = seconds * (total_seconds - seconds)
+/// Then solves this equation for distance_traveled(seconds) > distance_to_beat:
+/// distance_traveled(seconds) > distance_to_beat
+/// distance_traveled(seconds) - distance_to_beat > 0
+/// -seconds^2 + total_seconds*seconds - distance_to_beat > 0
+///
+/// Using the quadratic fourmula the intersections with 0 are found, and then it's just a matter of finding how many integers lie between them.
+/// It's all done in integers, since floats lose the last digits of 64 bit values: the integer square root gets the
+/// lower intersection to within a second, which is then nudged onto the first winning time. The winning times are
+/// symmetric around `total_seconds / 2`, so the last one mirrors it.
+fn count_possible_wins(total_seconds: u64, distance_to_beat: u64) -> u64 {
+    let (t, d) = (u128::from(total_seconds), u128::from(distance_to_beat));
+    // Without two intersections the record can at best be tied
+    if t * t <= 4 * d || !wins(t / 2, t, d) {
+        return 0;
+    }
+    let mut first = (t - (t * t - 4 * d).isqrt()) / 2;
+    while first > 0 && wins(first - 1, t, d) {
+        first -= 1;
+    }
+    while !wins(first, t, d) {
+        first += 1;
+    }
+    // `first` is at most half of the time, so this fits back in 64 bits
+    (t - 2 * first + 1) as u64
+}
+
+fn find_possible_win_products(games: &[Game]) -> Result<u64, SolveError> {
+    games
+        .iter()
+        .map(|g| count_possible_wins(g.total_seconds(), g.distance_to_beat()))
+        .checked_product()
+        .or_overflow("the product of the ways to win")
+}
+
+/// Time:      7  15   30
+/// Distance:  9  40  200
+fn parse_games(s: &str) -> Result<Vec<Game>, ParseError> {
+    let mut lines = s.lines();
+    let mut parse_row = |line_number: usize, header: &str| -> Result<Vec<u64>, ParseError> {
+        let line = lines.next().unwrap_or("");
+        let on_line = |kind| ParseError::new(kind).in_line(line_number, line);
+        let values = line
+            .strip_prefix(header)
+            .ok_or_else(|| on_line(ParseErrorKind::BadHeader(line.to_string())))?;
+        let values = values
+            .split_whitespace()
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| on_line(ParseErrorKind::BadNumber(v.to_string())))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::NoRaces(line.to_string()))
+                .with_span(0..line.len())
+                .in_line(line_number, line));
+        }
+        Ok(values)
+    };
+    let times = parse_row(1, "Time:")?;
+    let distances = parse_row(2, "Distance:")?;
+    if times.len() != distances.len() {
+        let line = s.lines().nth(1).unwrap_or_default();
+        return Err(
+            ParseError::new(ParseErrorKind::RaceCountMismatch(line.to_string())).in_line(2, line),
+        );
+    }
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Game(time, distance))
+        .collect())
+}
+
+fn write_games(games: &[Game]) -> String {
+    let row = |header: &str, value: fn(&Game) -> u64| {
+        let values: String = games.iter().map(|g| format!(" {}", value(g))).collect();
+        format!("{header}{values}\n")
+    };
+    row("Time:", Game::total_seconds) + &row("Distance:", Game::distance_to_beat)
+}
+
+/// The spaces between the numbers turn out to be bad kerning, so the games are really one long game.
+/// Like the values read, its time and distance have to fit in 64 bits.
+fn merge_games(games: &[Game]) -> Result<Game, SolveError> {
+    if games.is_empty() {
+        return Err(SolveError::Unsupported(
+            "there are no races to merge".to_string(),
+        ));
+    }
+    let concat = |value: fn(&Game) -> u64, what: &str| -> Result<u64, SolveError> {
+        games
+            .iter()
+            .map(|g| value(g).to_string())
+            .collect::<String>()
+            .parse::<u64>()
+            .ok()
+            .or_overflow(what)
+    };
+    Ok(Game(
+        concat(Game::total_seconds, "the merged race's time")?,
+        concat(Game::distance_to_beat, "the merged race's distance")?,
+    ))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Game>;
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        parse_games(input)
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        find_possible_win_products(input).map(Answer::from)
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        let game = merge_games(input)?;
+        Ok(count_possible_wins(game.total_seconds(), game.distance_to_beat()).into())
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(write_games(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz::check_round_trip, read_input, Answer};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Tries every way of holding the button.
+    fn count_possible_wins_brute_force(total_seconds: u64, distance_to_beat: u64) -> u64 {
+        (0..=total_seconds)
+            .filter(|&held| held * (total_seconds - held) > distance_to_beat)
+            .count() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn test_count_possible_wins_matches_brute_force(
+            // The best possible distance is (total_seconds / 2)^2, where wins are the hardest to count
+            (total_seconds, distance_to_beat) in (0..10_000u64).prop_flat_map(|t| {
+                let best = t * t / 4;
+                (Just(t), prop_oneof![0..=best + 10, best.saturating_sub(2)..=best + 2])
+            })
+        ) {
+            prop_assert_eq!(
+                count_possible_wins(total_seconds, distance_to_beat),
+                count_possible_wins_brute_force(total_seconds, distance_to_beat)
+            );
+        }
+
+        /// Races too long to try every time. The wins have to be exactly the times from `first` to
+        /// `total_seconds - first`, with `first` the earliest time that wins.
+        #[test]
+        fn test_count_possible_wins_on_long_races(
+            (total_seconds, distance_to_beat) in prop_oneof![1u64 << 20..1 << 33, any::<u64>()]
+                .prop_flat_map(|t| {
+                    let best = u64::try_from(u128::from(t) * u128::from(t) / 4).unwrap_or(u64::MAX);
+                    (Just(t), prop_oneof![any::<u64>(), best.saturating_sub(2)..=best])
+                })
+        ) {
+            let (t, d) = (u128::from(total_seconds), u128::from(distance_to_beat));
+            let count = u128::from(count_possible_wins(total_seconds, distance_to_beat));
+            if count == 0 {
+                prop_assert!(!wins(t / 2, t, d));
+            } else {
+                prop_assert_eq!((t + 1 - count) % 2, 0);
+                let first = (t + 1 - count) / 2;
+                prop_assert!(wins(first, t, d));
+                prop_assert!(first == 0 || !wins(first - 1, t, d));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_games() {
+        assert_eq!(
+            parse_games(&read_input("example.txt")).unwrap(),
+            vec![Game(7, 9), Game(15, 40), Game(30, 200)]
+        );
+        assert_eq!(
+            merge_games(&parse_games(&read_input("example.txt")).unwrap()),
+            Ok(Game(71530, 940200))
+        );
+    }
+
+    #[test]
+    fn test_merge_games_errors() {
+        assert_eq!(
+            merge_games(&[]),
+            Err(SolveError::Unsupported(
+                "there are no races to merge".to_string()
+            ))
+        );
+        assert_eq!(
+            merge_games(&[Game(1844674407, 0), Game(37095516150, 0)]),
+            Err(SolveError::Overflow("the merged race's time".to_string()))
+        );
+        assert_eq!(
+            merge_games(&[Game(1, 1844674407), Game(2, 37095516150)]),
+            Err(SolveError::Overflow(
+                "the merged race's distance".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_count_possible_wins() {
+        assert_eq!(count_possible_wins(7, 9), 4);
+        assert_eq!(count_possible_wins(15, 40), 8);
+        assert_eq!(count_possible_wins(30, 200), 9);
+        assert_eq!(count_possible_wins(71530, 940200), 71503);
+        // Holding for 2 seconds only ties the record
+        assert_eq!(count_possible_wins(4, 4), 0);
+        assert_eq!(count_possible_wins(3, 5), 0);
+        // Only holding for exactly half the time wins, which floats can't tell apart from a tie
+        assert_eq!(count_possible_wins(1 << 32, (1 << 62) - 1), 1);
+        assert_eq!(count_possible_wins(1 << 32, 1 << 62), 0);
+        assert_eq!(count_possible_wins(u64::MAX, u64::MAX), u64::MAX - 3);
+        assert_eq!(count_possible_wins(0, 0), 0);
+    }
+
+    #[test]
+    fn test_find_possible_win_products() {
+        assert_eq!(
+            find_possible_win_products(&[Game(7, 9), Game(15, 40), Game(30, 200)]),
+            Ok(288)
+        );
+        let long_races = parse_games("Time: 10000000 10000000 10000000\nDistance: 0 0 0").unwrap();
+        assert_eq!(
+            find_possible_win_products(&long_races),
+            Err(SolveError::Overflow(
+                "the product of the ways to win".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day6::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day6::part_1(&input), Ok(Answer::from(288u32)));
+        assert_eq!(Day6::part_2(&input), Ok(Answer::from(71503u32)));
+
+        let too_long = parse_games("Time: 1844674407 37095516150\nDistance: 1 2").unwrap();
+        assert_eq!(
+            Day6::part_2(&parse_games("Time: 4294967296\nDistance: 4611686018427387903").unwrap()),
+            Ok(Answer::from(1u32))
+        );
+        assert_eq!(
+            Day6::part_2(&too_long),
+            Err(SolveError::Overflow("the merged race's time".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_games("Time: 7 15\nDistance: 9 x"),
+            Err(ParseError {
+                line: 2,
+                span: Some(12..13),
+                kind: ParseErrorKind::BadNumber("x".to_string())
+            })
+        );
+        assert_eq!(
+            parse_games("Time: 7 15\n"),
+            Err(ParseError {
+                line: 2,
+                span: Some(0..0),
+                kind: ParseErrorKind::BadHeader("".to_string())
+            })
+        );
+        assert_eq!(
+            parse_games("Time: 7 15\nDistance: 9"),
+            Err(ParseError {
+                line: 2,
+                span: Some(0..11),
+                kind: ParseErrorKind::RaceCountMismatch("Distance: 9".to_string())
+            })
+        );
+        // Without races, part 1 would come out as the empty product
+        assert_eq!(
+            parse_games("Time:\nDistance:\n"),
+            Err(ParseError {
+                line: 1,
+                span: Some(0..5),
+                kind: ParseErrorKind::NoRaces("Time:".to_string())
+            })
+        );
+        assert_eq!(
+            parse_games("Time: 7\nDistance:  "),
+            Err(ParseError {
+                line: 2,
+                span: Some(0..11),
+                kind: ParseErrorKind::NoRaces("Distance:  ".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day6>(read_input("example.txt").as_bytes()).is_some());
+        assert!(check_round_trip::<Day6>(b"Time: 18446744073709551615\nDistance: 0").is_some());
+    }
+}
@@ -0,0 +1,480 @@
+use std::{collections::HashMap, fmt};
+
+use aoc_common::{
+    checked::OrOverflow,
+    generate::{InputRng, Rng},
+    parse::{parse_lines, ErrorKind},
+    Answer, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    BadCard(String),
+    BadHandSize(String),
+    MissingBid(String),
+    BadBid(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::BadCard(token)
+            | Self::BadHandSize(token)
+            | Self::MissingBid(token)
+            | Self::BadBid(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::BadCard(_) => "a card out of A, K, Q, J, T or 2-9",
+            Self::BadHandSize(_) => "a hand of 5 cards",
+            Self::MissingBid(_) => "a hand followed by its bid, like \"32T3K 765\"",
+            Self::BadBid(_) => "a bid amount",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+const HAND_SIZE: usize = 5;
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Hand([CardValue; HAND_SIZE]);
+
+#[derive(Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum CardValue {
+    Joker,
+    Number(u32),
+}
+
+impl CardValue {
+    const JACK: CardValue = CardValue::Number(11);
+
+    fn from_char(ch: char) -> Result<Self, ParseErrorKind> {
+        match ch {
+            'J' => Ok(Self::JACK),
+            'A' => Ok(Self::Number(14)),
+            'K' => Ok(Self::Number(13)),
+            'Q' => Ok(Self::Number(12)),
+            'T' => Ok(Self::Number(10)),
+            '2'..='9' => Ok(Self::Number(ch as u32 - '0' as u32)),
+            other => Err(ParseErrorKind::BadCard(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for CardValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = match *self {
+            // Jokers only replace jacks when playing with them
+            Self::Joker | Self::JACK => 'J',
+            Self::Number(14) => 'A',
+            Self::Number(13) => 'K',
+            Self::Number(12) => 'Q',
+            Self::Number(10) => 'T',
+            Self::Number(n) => char::from_digit(n, 10).expect("Other cards are single digits"),
+        };
+        write!(f, "{ch}")
+    }
+}
+
+fn get_distinct_counts(vals: &[u32]) -> Vec<usize> {
+    if vals.is_empty() {
+        return vec![0];
+    }
+
+    let mut collector: HashMap<u32, usize> = HashMap::default();
+    vals.iter().for_each(|&v| {
+        collector
+            .entry(v)
+            .and_modify(|n| {
+                *n += 1;
+            })
+            .or_insert(1);
+    });
+    collector.into_values().collect()
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum HandVariation {
+    HighCard,
+    OnePair,
+    TwoPairs,
+    ThreeOAK,
+    FullHouse,
+    FourOAK,
+    FiveOAK,
+}
+
+impl HandVariation {
+    fn from_distinct_counts(counts: &[usize]) -> Self {
+        let mut counts: Vec<_> = counts.into();
+        counts.sort();
+
+        if counts == vec![5] {
+            Self::FiveOAK
+        } else if counts == vec![1, 4] {
+            Self::FourOAK
+        } else if counts == vec![2, 3] {
+            Self::FullHouse
+        } else if counts == vec![1, 1, 3] {
+            Self::ThreeOAK
+        } else if counts == vec![1, 2, 2] {
+            Self::TwoPairs
+        } else if counts == vec![1, 1, 1, 2] {
+            Self::OnePair
+        } else {
+            Self::HighCard
+        }
+    }
+}
+
+impl Hand {
+    fn variation(&self) -> HandVariation {
+        let mut numbers = vec![];
+        let mut jokers = 0;
+
+        for val in &self.0 {
+            match val {
+                CardValue::Number(n) => numbers.push(*n),
+                CardValue::Joker => jokers += 1,
+            }
+        }
+
+        let mut counts = get_distinct_counts(&numbers);
+        counts.sort();
+        *counts.last_mut().unwrap() += jokers;
+        HandVariation::from_distinct_counts(&counts)
+    }
+
+    /// When playing with jokers, every 'J' is a wildcard that's weaker than every other card.
+    fn with_jokers(&self) -> Self {
+        Self(self.0.map(|val| {
+            if val == CardValue::JACK {
+                CardValue::Joker
+            } else {
+                val
+            }
+        }))
+    }
+
+    /// The errors point into `s`, which is where hands start on their line too.
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let cards = s
+            .char_indices()
+            .map(|(i, ch)| {
+                CardValue::from_char(ch)
+                    .map_err(|kind| ParseError::new(kind).with_span(i..i + ch.len_utf8()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(cards.try_into().map_err(|_| {
+            ParseError::new(ParseErrorKind::BadHandSize(s.to_string())).with_span(0..s.len())
+        })?))
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|card| write!(f, "{card}"))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.variation(), &self.0).cmp(&(other.variation(), &other.0))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_bids(s: &str) -> Result<Vec<(Hand, usize)>, ParseError> {
+    parse_lines(s, |l| {
+        let (hand_repr, bid) = l.split_once(' ').ok_or_else(|| {
+            ParseError::new(ParseErrorKind::MissingBid(l.to_string())).with_span(0..l.len())
+        })?;
+        let hand = Hand::parse(hand_repr)?;
+        let bid = bid.parse().map_err(|_| {
+            ParseError::new(ParseErrorKind::BadBid(bid.to_string()))
+                .with_span(hand_repr.len() + 1..l.len())
+        })?;
+        Ok((hand, bid))
+    })
+}
+
+fn find_total_winnings(bids: &[(Hand, usize)], jokers: bool) -> Result<usize, SolveError> {
+    let mut hands: Vec<(Hand, usize)> = bids
+        .iter()
+        .map(|(hand, bid)| {
+            let hand = if jokers {
+                hand.with_jokers()
+            } else {
+                hand.clone()
+            };
+            (hand, *bid)
+        })
+        .collect();
+
+    // Sort will put worst hands in front, which is great for index * hand bid
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .try_fold(0usize, |total, (i, (_hand, bid))| {
+            (i + 1)
+                .checked_mul(*bid)
+                .and_then(|winnings| total.checked_add(winnings))
+                .or_overflow("the total winnings")
+        })
+}
+
+/// Hands with a bid up to 1000 each. Jokers are drawn more often than other cards,
+/// so plenty of hands get stronger in part 2.
+fn generate_bids(hands: usize, rng: &mut InputRng) -> String {
+    const CARDS: &[u8] = b"23456789TJQKAJJJ";
+    let mut bids = String::new();
+    for _ in 0..hands {
+        let hand: String = (0..5)
+            .map(|_| CARDS[rng.gen_range(0..CARDS.len())] as char)
+            .collect();
+        bids.push_str(&format!("{hand} {}\n", rng.gen_range(1..=1000)));
+    }
+    bids
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(Hand, usize)>;
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        parse_bids(input)
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        find_total_winnings(input, false).map(Answer::from)
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        find_total_winnings(input, true).map(Answer::from)
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(
+            input
+                .iter()
+                .map(|(hand, bid)| format!("{hand} {bid}\n"))
+                .collect(),
+        )
+    }
+
+    /// `size` hands
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_bids(size, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input, Answer,
+    };
+
+    use super::*;
+
+    type CV = CardValue;
+    type HV = HandVariation;
+
+    fn assert_eq_sorted(a: &[usize], b: &[usize]) {
+        let mut a = Vec::from_iter(a.iter());
+        let mut b = Vec::from_iter(b.iter());
+        a.sort();
+        b.sort();
+        assert_eq!(a, b)
+    }
+
+    #[test]
+    fn test_get_distinct_counts() {
+        assert_eq_sorted(&get_distinct_counts(&[1, 2, 3, 4, 5]), &[1, 1, 1, 1, 1]);
+        assert_eq_sorted(&get_distinct_counts(&[1, 1, 3, 4, 5]), &[2, 1, 1, 1]);
+        assert_eq_sorted(&get_distinct_counts(&[1, 1, 1, 1, 1]), &[5]);
+    }
+
+    #[test]
+    fn test_hand_variation_from_counts() {
+        assert_eq!(HV::from_distinct_counts(&[5]), HV::FiveOAK);
+        assert_eq!(HV::from_distinct_counts(&[1, 4]), HV::FourOAK);
+        assert_eq!(HV::from_distinct_counts(&[3, 2]), HV::FullHouse);
+        assert_eq!(HV::from_distinct_counts(&[3, 1, 1]), HV::ThreeOAK);
+        assert_eq!(HV::from_distinct_counts(&[2, 1, 2]), HV::TwoPairs);
+        assert_eq!(HV::from_distinct_counts(&[1, 1, 1, 2]), HV::OnePair);
+        assert_eq!(HV::from_distinct_counts(&[1, 1, 1, 1, 1]), HV::HighCard);
+    }
+
+    #[test]
+    fn test_hand_comparison() {
+        assert_eq!(Hand::parse("32T3K").unwrap(), Hand::parse("32T3K").unwrap());
+        // Test hand variation precedence
+        assert!(Hand::parse("22223").unwrap() > Hand::parse("33445").unwrap());
+
+        // Test value precedence
+        assert!(Hand::parse("32222").unwrap() > Hand::parse("22223").unwrap());
+
+        // Test Joker is weakest
+        assert!(
+            Hand::parse("22222").unwrap().with_jokers()
+                > Hand::parse("JJJJJ").unwrap().with_jokers()
+        );
+
+        // Without jokers, J is a jack that ranks between T and Q
+        assert!(Hand::parse("JJJJJ").unwrap() > Hand::parse("TTTTT").unwrap());
+        assert!(Hand::parse("JJJJJ").unwrap() < Hand::parse("QQQQQ").unwrap());
+        assert!(Hand::parse("KTJJT").unwrap() < Hand::parse("KK677").unwrap());
+    }
+
+    #[test]
+    fn test_parse_hand() {
+        assert_eq!(
+            Hand::parse("AK9TQ").unwrap(),
+            Hand([
+                CV::Number(14),
+                CV::Number(13),
+                CV::Number(9),
+                CV::Number(10),
+                CV::Number(12)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_find_total_winnings() {
+        assert_eq!(
+            find_total_winnings(&parse_bids(&read_input("example.txt")).unwrap(), false),
+            Ok(6440)
+        );
+        assert_eq!(
+            find_total_winnings(&parse_bids(&read_input("example.txt")).unwrap(), true),
+            Ok(5905)
+        );
+        let bids = parse_bids(&format!("22222 1\n33333 {}", usize::MAX / 2 + 1)).unwrap();
+        assert_eq!(
+            find_total_winnings(&bids, false),
+            Err(SolveError::Overflow("the total winnings".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_hand_variation_with_jokers() {
+        assert_eq!(
+            Hand::parse("JJJJJ").unwrap().with_jokers().variation(),
+            HV::FiveOAK
+        );
+        assert_eq!(
+            Hand::parse("JJQJJ").unwrap().with_jokers().variation(),
+            HV::FiveOAK
+        );
+        assert_eq!(
+            Hand::parse("5234J").unwrap().with_jokers().variation(),
+            HV::OnePair
+        );
+        assert_eq!(
+            Hand::parse("5334J").unwrap().with_jokers().variation(),
+            HV::ThreeOAK
+        );
+        assert_eq!(
+            Hand::parse("4334J").unwrap().with_jokers().variation(),
+            HV::FullHouse
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day7::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day7::part_1(&input), Ok(Answer::from(6440usize)));
+        assert_eq!(Day7::part_2(&input), Ok(Answer::from(5905usize)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Hand::parse("32X3K"),
+            Err(ParseError::new(ParseErrorKind::BadCard("X".to_string())).with_span(2..3))
+        );
+        // Cards only go down to 2
+        assert_eq!(
+            CardValue::from_char('0'),
+            Err(ParseErrorKind::BadCard("0".to_string()))
+        );
+        assert_eq!(
+            CardValue::from_char('1'),
+            Err(ParseErrorKind::BadCard("1".to_string()))
+        );
+        assert_eq!(CardValue::from_char('2'), Ok(CardValue::Number(2)));
+        assert_eq!(CardValue::from_char('9'), Ok(CardValue::Number(9)));
+        assert_eq!(
+            Hand::parse("32T3"),
+            Err(ParseError::new(ParseErrorKind::BadHandSize("32T3".to_string())).with_span(0..4))
+        );
+        assert_eq!(
+            parse_bids("32T3K 765\nT55J5"),
+            Err(ParseError {
+                line: 2,
+                span: Some(0..5),
+                kind: ParseErrorKind::MissingBid("T55J5".to_string())
+            })
+        );
+        assert_eq!(
+            parse_bids("32T3K 7a5"),
+            Err(ParseError {
+                line: 1,
+                span: Some(6..9),
+                kind: ParseErrorKind::BadBid("7a5".to_string())
+            })
+        );
+        // The bid looks like part of the hand, but the error points at the bid
+        assert_eq!(
+            parse_bids("33333 3a"),
+            Err(ParseError {
+                line: 1,
+                span: Some(6..8),
+                kind: ParseErrorKind::BadBid("3a".to_string())
+            })
+        );
+        // With a bad hand and a bad bid, the hand comes first
+        assert_eq!(
+            parse_bids("3a3a3 3a"),
+            Err(ParseError {
+                line: 1,
+                span: Some(1..2),
+                kind: ParseErrorKind::BadCard("a".to_string())
+            })
+        );
+        assert_eq!(
+            parse_bids("32T3KK 1"),
+            Err(ParseError {
+                line: 1,
+                span: Some(0..6),
+                kind: ParseErrorKind::BadHandSize("32T3KK".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day7>(100);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day7>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day7::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day7>(generated.as_bytes()).is_some());
+    }
+}
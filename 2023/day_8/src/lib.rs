@@ -0,0 +1,775 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    checked::OrOverflow,
+    generate::{InputRng, Rng, SliceRandom},
+    input::Section,
+    math::{crt_u128, CrtError},
+    parse::{ErrorKind, Field, Pattern},
+    visualize::{dot_quote, Visualization},
+    Answer, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    MissingDirections(String),
+    BadDirection(String),
+    MissingBlankLine(String),
+    BadNode(String),
+    UnknownNode(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::MissingDirections(token)
+            | Self::BadDirection(token)
+            | Self::MissingBlankLine(token)
+            | Self::BadNode(token)
+            | Self::UnknownNode(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::MissingDirections(_) => "a line of L and R directions",
+            Self::BadDirection(_) => "L or R",
+            Self::MissingBlankLine(_) => "a blank line after the directions",
+            Self::BadNode(_) => "a node like \"AAA = (BBB, CCC)\"",
+            Self::UnknownNode(_) => "a node that's defined in the map",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+#[derive(Default, PartialEq, Debug)]
+struct Map {
+    directions: HashMap<String, (String, String)>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+impl Map {
+    fn add_direction(
+        &mut self,
+        from: impl Into<String>,
+        left: impl Into<String>,
+        right: impl Into<String>,
+    ) {
+        self.directions
+            .insert(from.into(), (left.into(), right.into()));
+    }
+
+    fn step(&self, from: &str, direction: Direction) -> &str {
+        let options = &self.directions[from];
+        match direction {
+            Direction::Left => &options.0,
+            Direction::Right => &options.1,
+        }
+    }
+
+    /// line format is:
+    /// AAA = (BBB, CCC)
+    /// START = (LEFT, RIGHT)
+    fn add_direction_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let [from, left, right] = Self::parse_direction_line(line)?;
+        self.add_direction(from.text, left.text, right.text);
+        Ok(())
+    }
+
+    /// Node names are always 3 characters long.
+    fn parse_direction_line(line: &str) -> Result<[Field<'_>; 3], ParseError> {
+        let line = Field::new(line);
+        let nodes = Pattern::new("{} = ({}, {})").fields(line, ParseErrorKind::BadNode)?;
+        if nodes.iter().any(|node| node.text.chars().count() != 3) {
+            return Err(line.error(ParseErrorKind::BadNode));
+        }
+        Ok(nodes)
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Puzzle {
+    map: Map,
+    directions: Vec<Direction>,
+}
+
+impl Puzzle {
+    /// The directions come first, and then the nodes after one or more blank lines.
+    fn from_str(input: &PuzzleInput) -> Result<Puzzle, ParseError> {
+        let sections = input.sections();
+        let Some((first, nodes)) = sections.split_first() else {
+            return Err(
+                ParseError::new(ParseErrorKind::MissingDirections(String::new())).in_line(1, ""),
+            );
+        };
+        let mut first_lines = first.lines();
+        let (line_number, first_line) = first_lines.next().expect("Sections aren't empty");
+        let directions = Field::new(first_line)
+            .chars()
+            .map(|ch| {
+                ch.text
+                    .chars()
+                    .next()
+                    .and_then(Direction::from_char)
+                    .ok_or_else(|| ch.error(ParseErrorKind::BadDirection))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| err.in_line(line_number, first_line))?;
+
+        if let Some((i, line)) = first_lines.next() {
+            return Err(
+                ParseError::new(ParseErrorKind::MissingBlankLine(line.to_string()))
+                    .in_line(i, line),
+            );
+        }
+
+        let mut map = Map::default();
+        let mut node_lines = vec![];
+        for (i, line) in nodes.iter().flat_map(Section::lines) {
+            map.add_direction_line(line)
+                .map_err(|err| err.in_line(i, line))?;
+            node_lines.push((i, line));
+        }
+
+        // Make sure stepping can never walk off the map
+        for (i, line) in node_lines {
+            let [_from, left, right] =
+                Map::parse_direction_line(line).expect("Line was already parsed");
+            if let Some(unknown) = [left, right]
+                .into_iter()
+                .find(|node| !map.directions.contains_key(node.text))
+            {
+                return Err(unknown.error(ParseErrorKind::UnknownNode).in_line(i, line));
+            }
+        }
+
+        Ok(Self { map, directions })
+    }
+
+    fn write_directions(&self) -> String {
+        self.directions
+            .iter()
+            .map(|direction| match direction {
+                Direction::Left => 'L',
+                Direction::Right => 'R',
+            })
+            .collect()
+    }
+
+    /// The nodes sorted by name, since the map doesn't keep their order.
+    fn sorted_nodes(&self) -> Vec<(&String, &(String, String))> {
+        let mut nodes: Vec<_> = self.map.directions.iter().collect();
+        nodes.sort();
+        nodes
+    }
+
+    fn write(&self) -> String {
+        let nodes: String = self
+            .sorted_nodes()
+            .into_iter()
+            .map(|(from, (left, right))| format!("{from} = ({left}, {right})\n"))
+            .collect();
+        format!("{}\n\n{nodes}", self.write_directions())
+    }
+
+    /// The map as a Graphviz graph, with an edge for each way out of a node, labelled with the direction taking it.
+    /// The nodes ghosts start from are green and the ones they're headed for red.
+    fn write_dot(&self) -> String {
+        let mut dot = format!(
+            "digraph map {{\n  // Directions: {}\n  node [shape=circle];\n",
+            self.write_directions()
+        );
+        for (from, (left, right)) in self.sorted_nodes() {
+            let from = dot_quote(from);
+            if left == right {
+                dot += &format!("  {from} -> {} [label=\"LR\"];\n", dot_quote(left));
+            } else {
+                dot += &format!("  {from} -> {} [label=\"L\"];\n", dot_quote(left));
+                dot += &format!("  {from} -> {} [label=\"R\"];\n", dot_quote(right));
+            }
+        }
+        for (node, _) in self.sorted_nodes() {
+            let colour = match node.chars().last() {
+                Some('A') => "palegreen",
+                Some('Z') => "lightcoral",
+                _ => continue,
+            };
+            dot += &format!(
+                "  {} [style=filled, fillcolor={colour}];\n",
+                dot_quote(node)
+            );
+        }
+        dot += "}\n";
+        dot
+    }
+
+    fn count_steps(&self, from: &str, to: &str) -> Option<usize> {
+        self.count_simultanious_steps(&[from], &[to])
+    }
+
+    fn count_simultanious_steps(&self, froms: &[&str], tos: &[&str]) -> Option<usize> {
+        if froms.len() != tos.len() || froms.is_empty() {
+            return None;
+        };
+        self.count_simultanious_steps_until(froms, |currents| currents == tos)
+            .map(|(steps, _ends)| steps)
+    }
+
+    /// Walks from `froms` until `check_fn` accepts where the walk got to. Gives up once the walk is back on the same
+    /// nodes at the same point of the directions, since from there it only goes around in circles.
+    fn count_simultanious_steps_until<'a>(
+        &'a self,
+        froms: &[&'a str],
+        mut check_fn: impl FnMut(&[&str]) -> bool,
+    ) -> Option<(usize, Vec<&'a str>)> {
+        if froms.is_empty() {
+            return None;
+        };
+
+        let mut currents: Vec<&str> = froms.into();
+        let mut seen = HashSet::new();
+        let mut steps = 0;
+        loop {
+            if check_fn(&currents) {
+                return Some((steps, currents));
+            }
+            if !seen.insert((currents.clone(), steps % self.directions.len())) {
+                return None;
+            }
+            currents.iter_mut().for_each(|val| {
+                *val = self
+                    .map
+                    .step(val, self.directions[steps % self.directions.len()])
+            });
+            steps += 1;
+        }
+    }
+}
+
+/// A ghost's walk, which ends up looping through a single Z node.
+#[derive(PartialEq, Eq, Debug)]
+struct GhostLoop {
+    /// The number of steps to the Z node the first time
+    first: usize,
+    /// The number of steps it takes to get back to it
+    period: usize,
+}
+
+fn get_puzzle_loops(puzzle: &Puzzle, starts: &[&str]) -> Result<Vec<GhostLoop>, String> {
+    let mut res = vec![];
+    for start in starts {
+        let (steps, z_val) = puzzle
+            .count_simultanious_steps_until(&[start], |s| s.iter().all(|l| l.ends_with('Z')))
+            .ok_or_else(|| format!("The ghost starting on {start} never gets to a Z node"))?;
+        let z_val = z_val[0];
+        let mut first = true;
+
+        let (next_steps, next_z_val) = puzzle
+            .count_simultanious_steps_until(&[z_val], |l| {
+                if first {
+                    first = false;
+                    return false;
+                }
+                l.iter().any(|n| n.ends_with('Z'))
+            })
+            .ok_or_else(|| format!("The ghost never gets back to a Z node after {z_val}"))?;
+
+        let next_z_val = next_z_val[0];
+
+        if next_z_val != z_val {
+            return Err(format!(
+                "More than 1 Z on track! Found {} then {}",
+                z_val, next_z_val
+            ));
+        }
+        // The walk back to the Z node starts over from the first direction, so it's only the ghost's own walk
+        // if it got to the Z node as the directions started over, and only a loop if it gets back to it as they do
+        if steps % puzzle.directions.len() != 0 || next_steps % puzzle.directions.len() != 0 {
+            return Err("Loop doesn't conform with direction count and so isn't easily computable. Not supported!".to_string());
+        }
+        res.push(GhostLoop {
+            first: steps,
+            period: next_steps,
+        })
+    }
+
+    Ok(res)
+}
+
+/// The first step at which every ghost is on its Z node: it's at one every `period` steps from its `first` on,
+/// which the chinese remainder theorem can line up.
+fn get_first_common_step(loops: &[GhostLoop]) -> Result<u64, SolveError> {
+    let congruences: Vec<(u128, u128)> = loops
+        .iter()
+        .map(|l| (l.first as u128, l.period as u128))
+        .collect();
+    let (mut step, period) = crt_u128(&congruences).map_err(|err| match err {
+        CrtError::NoSolution => SolveError::Unsupported(err.to_string()),
+        CrtError::Overflow => {
+            SolveError::Overflow("the period the ghosts line up with".to_string())
+        }
+    })?;
+    // The solution can come before some ghost has reached its loop
+    let latest_first = loops.iter().map(|l| l.first as u128).max().unwrap_or(0);
+    if step < latest_first {
+        step += (latest_first - step).div_ceil(period) * period;
+    }
+    u64::try_from(step)
+        .ok()
+        .or_overflow("the step the ghosts line up on")
+}
+
+fn count_ghost_steps(puzzle: &Puzzle) -> Result<u64, SolveError> {
+    let starts: Vec<_> = puzzle
+        .map
+        .directions
+        .keys()
+        .filter(|&d| d.ends_with('A'))
+        .map(|v| v.as_str())
+        .collect();
+    if starts.is_empty() {
+        return Err(SolveError::Unsupported(
+            "The map has no nodes ending in A".to_string(),
+        ));
+    }
+
+    let loops = get_puzzle_loops(puzzle, &starts).map_err(SolveError::Unsupported)?;
+
+    get_first_common_step(&loops)
+}
+
+/// Makes up a node name ending with `last` that isn't taken yet.
+fn generate_node_name(rng: &mut InputRng, taken: &mut HashSet<String>, last: char) -> String {
+    loop {
+        let name: String = [rng.gen_range('A'..='Z'), rng.gen_range('A'..='Z'), last]
+            .into_iter()
+            .collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// A map with the structure the real ones have: every ghost walks a loop that takes it from its A node to its Z node
+/// in a number of rounds of the directions, and then back to the Z node every as many steps. The numbers of rounds
+/// are distinct primes, and the first ghost walks from AAA to ZZZ.
+///
+/// Ghosts always reach a node of their loop at the same point of the directions, so the direction they don't take
+/// from it can lead anywhere.
+fn generate_map(directions: usize, rng: &mut InputRng) -> String {
+    const ROUNDS: &[usize] = &[3, 5, 7, 11, 13];
+    let directions: Vec<char> = (0..directions)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    let mut rounds = ROUNDS.to_vec();
+    rounds.shuffle(rng);
+    rounds.truncate(rng.gen_range(2..=4));
+
+    let mut taken = HashSet::new();
+    // Each node along with the direction the ghosts take from it and where it leads them
+    let mut nodes: Vec<(String, char, String)> = vec![];
+    for (ghost, &rounds) in rounds.iter().enumerate() {
+        let (start, end) = if ghost == 0 {
+            taken.extend(["AAA".to_string(), "ZZZ".to_string()]);
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (
+                generate_node_name(rng, &mut taken, 'A'),
+                generate_node_name(rng, &mut taken, 'Z'),
+            )
+        };
+        let steps = rounds * directions.len();
+        let mut path = vec![start];
+        for _ in 1..steps {
+            let last = rng.gen_range('B'..='Y');
+            path.push(generate_node_name(rng, &mut taken, last));
+        }
+        path.push(end.clone());
+        for (step, window) in path.windows(2).enumerate() {
+            nodes.push((
+                window[0].clone(),
+                directions[step % directions.len()],
+                window[1].clone(),
+            ));
+        }
+        // Back around the loop, at the start of the directions again
+        nodes.push((end, directions[0], path[1].clone()));
+    }
+
+    let names: Vec<String> = nodes.iter().map(|(name, _, _)| name.clone()).collect();
+    nodes.shuffle(rng);
+    let mut map = format!("{}\n\n", directions.iter().collect::<String>());
+    for (name, direction, next) in nodes {
+        let other = names.choose(rng).unwrap();
+        let (left, right) = match direction {
+            'L' => (&next, other),
+            _ => (other, &next),
+        };
+        map.push_str(&format!("{name} = ({left}, {right})\n"));
+    }
+    map
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Puzzle;
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        Puzzle::from_str(input)
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        if !input.map.directions.contains_key("AAA") {
+            return Err(SolveError::Unsupported(
+                "The map has no AAA node".to_string(),
+            ));
+        }
+        input
+            .count_steps("AAA", "ZZZ")
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::Unsupported("ZZZ can't be reached from AAA".to_string()))
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        count_ghost_steps(input).map(Answer::from)
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(input.write())
+    }
+
+    fn visualize(input: &Self::Input) -> Vec<Visualization> {
+        vec![Visualization::dot(input.write_dot())]
+    }
+
+    /// `size` directions, up to 400 so there are enough node names to go around
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_map(size.clamp(1, 400), rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input, Answer,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_direction_from_char() {
+        assert_eq!(Direction::from_char('R'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('L'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('n'), None);
+    }
+
+    #[test]
+    fn test_step() {
+        let mut map = Map::default();
+        map.add_direction("AAA", "BBB", "CCC");
+        assert_eq!(map.step("AAA", Direction::Right), "CCC");
+        assert_eq!(map.step("AAA", Direction::Left), "BBB");
+    }
+
+    #[test]
+    fn test_parse_direction_line() {
+        assert_eq!(
+            Map::parse_direction_line("AAA = (BBB, CCC)").map(|nodes| nodes.map(|node| node.text)),
+            Ok(["AAA", "BBB", "CCC"])
+        );
+        assert_eq!(
+            Map::parse_direction_line("AAA = BBB, CCC)"),
+            Err(
+                ParseError::new(ParseErrorKind::BadNode("AAA = BBB, CCC)".to_string()))
+                    .with_span(0..15)
+            )
+        );
+        assert_eq!(
+            Map::parse_direction_line("AAAA = (BBB, CCC)").map_err(|err| err.kind),
+            Err(ParseErrorKind::BadNode("AAAA = (BBB, CCC)".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_add_direction() {
+        let mut map = Map::default();
+        // Add invalid direction:
+        assert_eq!(
+            map.add_direction_line("XXX = YYY, ZZZ"),
+            Err(
+                ParseError::new(ParseErrorKind::BadNode("XXX = YYY, ZZZ".to_string()))
+                    .with_span(0..14)
+            )
+        );
+
+        // Add valid direction
+        assert_eq!(map.add_direction_line("AAA = (BBB, CCC)"), Ok(()));
+        // Make sure was added:
+        assert_eq!(map.step("AAA", Direction::Right), "CCC");
+    }
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = Puzzle::from_str(&read_input("example.txt")).unwrap();
+        assert_eq!(
+            puzzle.directions,
+            vec![Direction::Left, Direction::Left, Direction::Right]
+        );
+        assert_eq!(
+            puzzle.map,
+            [
+                ("AAA", "BBB", "BBB"),
+                ("BBB", "AAA", "ZZZ"),
+                ("ZZZ", "ZZZ", "ZZZ")
+            ]
+            .into_iter()
+            .fold(Map::default(), |mut map, (from, left, right)| {
+                map.add_direction(from, left, right);
+                map
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_blank_lines() {
+        let puzzle =
+            Puzzle::from_str(&PuzzleInput::new("LR\r\n\r\n\r\nAAA = (AAA, AAA)\r\n")).unwrap();
+        assert_eq!(puzzle.directions, vec![Direction::Left, Direction::Right]);
+        assert_eq!(puzzle.map.step("AAA", Direction::Left), "AAA");
+    }
+
+    #[test]
+    fn test_solve_puzzle() {
+        let puzzle = Puzzle::from_str(&read_input("example.txt")).expect("Bad Puzzle");
+        assert_eq!(puzzle.count_steps("AAA", "ZZZ"), Some(6));
+        // BBB only leads back to AAA
+        let puzzle = Puzzle::from_str(&PuzzleInput::new(
+            "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)",
+        ))
+        .unwrap();
+        assert_eq!(puzzle.count_steps("AAA", "ZZZ"), None);
+    }
+
+    #[test]
+    fn test_solve_simultanious() {
+        let puzzle = Puzzle::from_str(&read_input("simultanious_example.txt")).expect("Bad Puzzle");
+        assert_eq!(
+            puzzle.count_simultanious_steps(&["11A", "22A"], &["11Z", "22Z"]),
+            Some(6)
+        )
+    }
+
+    #[test]
+    fn test_step_until_zs() {
+        let puzzle = Puzzle::from_str(&read_input("simultanious_example.txt")).expect("Bad Puzzle");
+        assert_eq!(
+            puzzle.count_simultanious_steps_until(&["11A", "22A"], |currents| currents
+                .iter()
+                .all(|l| l.ends_with('Z'))),
+            Some((6, vec!["11Z", "22Z"]))
+        )
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day8::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day8::part_1(&input), Ok(Answer::from(6usize)));
+
+        // The ghost on 22A loops every 3 steps, which doesn't line up with the 2 directions
+        let input = Day8::parse(&read_input("simultanious_example.txt")).unwrap();
+        assert!(matches!(
+            Day8::part_2(&input),
+            Err(SolveError::Unsupported(_))
+        ));
+
+        let input = Day8::parse(&PuzzleInput::new(
+            "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)",
+        ))
+        .unwrap();
+        assert_eq!(
+            Day8::part_1(&input),
+            Err(SolveError::Unsupported(
+                "ZZZ can't be reached from AAA".to_string()
+            ))
+        );
+        // 11A's ghost never reaches a Z node, and without A nodes there are no ghosts at all
+        let input =
+            Day8::parse(&PuzzleInput::new("L\n\n11A = (11B, 11B)\n11B = (11A, 11A)")).unwrap();
+        assert_eq!(
+            Day8::part_2(&input),
+            Err(SolveError::Unsupported(
+                "The ghost starting on 11A never gets to a Z node".to_string()
+            ))
+        );
+        let input =
+            Day8::parse(&PuzzleInput::new("L\n\n11B = (11Z, 11Z)\n11Z = (11B, 11B)")).unwrap();
+        assert_eq!(
+            Day8::part_2(&input),
+            Err(SolveError::Unsupported(
+                "The map has no nodes ending in A".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_write_dot() {
+        let input = Day8::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(
+            input.write_dot(),
+            "digraph map {\n  \
+             // Directions: LLR\n  \
+             node [shape=circle];\n  \
+             \"AAA\" -> \"BBB\" [label=\"LR\"];\n  \
+             \"BBB\" -> \"AAA\" [label=\"L\"];\n  \
+             \"BBB\" -> \"ZZZ\" [label=\"R\"];\n  \
+             \"ZZZ\" -> \"ZZZ\" [label=\"LR\"];\n  \
+             \"AAA\" [style=filled, fillcolor=palegreen];\n  \
+             \"ZZZ\" [style=filled, fillcolor=lightcoral];\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_ghosts_out_of_step() {
+        // 11Z is reached after 1 step and then every 2, 22Z after 3 and then every step, 33Z after 1 and then every 3
+        let puzzle = Puzzle::from_str(&PuzzleInput::new(
+            "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Z, 11Z)\n\
+             22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22Z, 22Z)\n\
+             33A = (33Z, 33Z)\n33Z = (33B, 33B)\n33B = (33C, 33C)\n33C = (33Z, 33Z)",
+        ))
+        .unwrap();
+        let loops = get_puzzle_loops(&puzzle, &["11A", "22A", "33A"]).unwrap();
+        assert_eq!(
+            loops
+                .into_iter()
+                .map(|l| (l.first, l.period))
+                .collect::<Vec<_>>(),
+            vec![(1, 2), (3, 1), (1, 3)]
+        );
+        assert_eq!(count_ghost_steps(&puzzle), Ok(7));
+        let (steps, _) = puzzle
+            .count_simultanious_steps_until(&["11A", "22A", "33A"], |currents| {
+                currents.iter().all(|node| node.ends_with('Z'))
+            })
+            .unwrap();
+        assert_eq!(steps, 7);
+    }
+
+    #[test]
+    fn test_first_common_step() {
+        let loops = |l: &[(usize, usize)]| -> Vec<GhostLoop> {
+            l.iter()
+                .map(|&(first, period)| GhostLoop { first, period })
+                .collect()
+        };
+        assert_eq!(get_first_common_step(&loops(&[(2, 2), (3, 3)])), Ok(6));
+        // Not the product over the gcd, which would be 48
+        assert_eq!(
+            get_first_common_step(&loops(&[(4, 4), (6, 6), (8, 8)])),
+            Ok(24)
+        );
+        // Lined up at step 0, before the second ghost has got anywhere
+        assert_eq!(get_first_common_step(&loops(&[(2, 2), (10, 5)])), Ok(10));
+        assert!(matches!(
+            get_first_common_step(&loops(&[(1, 2), (2, 4)])),
+            Err(SolveError::Unsupported(_))
+        ));
+        assert_eq!(
+            get_first_common_step(&loops(&[
+                (usize::MAX, usize::MAX),
+                (usize::MAX - 1, usize::MAX - 1)
+            ])),
+            Err(SolveError::Overflow(
+                "the step the ghosts line up on".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Puzzle::from_str(&PuzzleInput::new("")).err(),
+            Some(ParseError {
+                line: 1,
+                span: Some(0..0),
+                kind: ParseErrorKind::MissingDirections("".to_string())
+            })
+        );
+        assert_eq!(
+            Puzzle::from_str(&PuzzleInput::new("LXR")).err(),
+            Some(ParseError {
+                line: 1,
+                span: Some(1..2),
+                kind: ParseErrorKind::BadDirection("X".to_string())
+            })
+        );
+        assert_eq!(
+            Puzzle::from_str(&PuzzleInput::new("LR\nAAA = (AAA, AAA)")).err(),
+            Some(ParseError {
+                line: 2,
+                span: Some(0..16),
+                kind: ParseErrorKind::MissingBlankLine("AAA = (AAA, AAA)".to_string())
+            })
+        );
+        assert_eq!(
+            Puzzle::from_str(&PuzzleInput::new(
+                "LR\n\nAAA = (AAA, AAA)\nBBB = (AAA, CCC)"
+            ))
+            .err(),
+            Some(ParseError {
+                line: 4,
+                span: Some(12..15),
+                kind: ParseErrorKind::UnknownNode("CCC".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day8>(100);
+
+        // Small enough to walk the ghosts step by step
+        let puzzle =
+            Day8::parse(&PuzzleInput::new(&Day8::generate(5, &mut rng(1)).unwrap())).unwrap();
+        let starts: Vec<&str> = puzzle
+            .map
+            .directions
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .map(String::as_str)
+            .collect();
+        let (steps, _) = puzzle
+            .count_simultanious_steps_until(&starts, |currents| {
+                currents.iter().all(|node| node.ends_with('Z'))
+            })
+            .unwrap();
+        assert_eq!(Day8::part_2(&puzzle), Ok(Answer::from(steps)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day8>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day8::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day8>(generated.as_bytes()).is_some());
+    }
+}
@@ -0,0 +1,282 @@
+use aoc_common::{
+    checked::{CheckedIterator, OrOverflow},
+    generate::{InputRng, Rng},
+    parse::{parse_lines, ErrorKind},
+    Answer, PuzzleInput, Solution, SolveError, SolveResult,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    EmptyHistory(String),
+    BadNumber(String),
+}
+
+impl ErrorKind for ParseErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::EmptyHistory(token) | Self::BadNumber(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::EmptyHistory(_) => "at least one value",
+            Self::BadNumber(_) => "a number",
+        }
+    }
+}
+
+pub type ParseError = aoc_common::parse::ParseError<ParseErrorKind>;
+
+/// The differences are computed in 64 bits, since they can grow far beyond the values. `None` when they grow
+/// beyond that too.
+fn compute_diff_pyramid(vals: &[i32]) -> Option<Vec<Vec<i64>>> {
+    let mut steps: Vec<Vec<i64>> = vec![];
+    steps.push(vals.iter().map(|&v| v.into()).collect());
+    loop {
+        let last_steps = steps.last().expect("Steps can't be empty!");
+        if last_steps.iter().all(|&v| v == 0) {
+            break Some(steps);
+        }
+        let mut new_vec = vec![];
+        for i in 1..last_steps.len() {
+            new_vec.push(last_steps[i].checked_sub(last_steps[i - 1])?);
+        }
+        steps.push(new_vec);
+    }
+}
+
+/// A history that runs out of values before its differences reach zero ends with a single constant difference,
+/// so the empty row under it stands for zeros.
+fn extrapolate_history(vals: &[i32]) -> Option<i64> {
+    let diff_pyramid = compute_diff_pyramid(vals)?;
+    diff_pyramid
+        .iter()
+        .rev()
+        .map(|v| v.last().copied().unwrap_or(0))
+        .checked_sum()
+}
+
+fn extrapolate_history_backwards(vals: &[i32]) -> Option<i64> {
+    let diff_pyramid = compute_diff_pyramid(vals)?;
+    diff_pyramid
+        .iter()
+        .rev()
+        .map(|v| v.first().copied().unwrap_or(0))
+        .try_fold(0i64, |acc, el| el.checked_sub(acc))
+}
+
+fn parse_history(line: &str) -> Result<Vec<i32>, ParseError> {
+    if line.trim().is_empty() {
+        return Err(ParseErrorKind::EmptyHistory(line.to_string()).into());
+    }
+    line.split_whitespace()
+        .map(|v| {
+            v.parse()
+                .map_err(|_| ParseErrorKind::BadNumber(v.to_string()).into())
+        })
+        .collect()
+}
+
+fn parse_input(s: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_lines(s, parse_history)
+}
+
+fn sum_extrapolations(
+    histories: &[Vec<i32>],
+    extrapolate: fn(&[i32]) -> Option<i64>,
+) -> Result<i64, SolveError> {
+    histories.iter().try_fold(0i64, |sum, v| {
+        let value = extrapolate(v).or_overflow("a history's differences")?;
+        sum.checked_add(value)
+            .or_overflow("the sum of the extrapolated values")
+    })
+}
+
+fn find_extrapolation_sum(histories: &[Vec<i32>]) -> Result<i64, SolveError> {
+    sum_extrapolations(histories, extrapolate_history)
+}
+
+fn find_extrapolation_sum_backwards(histories: &[Vec<i32>]) -> Result<i64, SolveError> {
+    sum_extrapolations(histories, extrapolate_history_backwards)
+}
+
+/// Histories of 21 values sampled from polynomials of degree up to 4, which are the ones extrapolating works on.
+/// Their coefficients are kept small so the values still fit in 32 bits.
+fn generate_histories(histories: usize, rng: &mut InputRng) -> String {
+    let mut report = String::new();
+    for _ in 0..histories {
+        let coefficients: Vec<i32> = (0..=rng.gen_range(0..=4))
+            .map(|_| rng.gen_range(-3..=3))
+            .collect();
+        let values: Vec<String> = (0..21)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, &c| acc * x + c)
+                    .to_string()
+            })
+            .collect();
+        report.push_str(&values.join(" "));
+        report.push('\n');
+    }
+    report
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+    type Error = ParseError;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> SolveResult {
+        find_extrapolation_sum(input).map(Answer::from)
+    }
+
+    fn part_2(input: &Self::Input) -> SolveResult {
+        find_extrapolation_sum_backwards(input).map(Answer::from)
+    }
+
+    fn serialize(input: &Self::Input) -> Option<String> {
+        Some(
+            input
+                .iter()
+                .map(|history| {
+                    let values: Vec<String> = history.iter().map(|v| v.to_string()).collect();
+                    values.join(" ") + "\n"
+                })
+                .collect(),
+        )
+    }
+
+    /// `size` histories
+    fn generate(size: usize, rng: &mut InputRng) -> Option<String> {
+        Some(generate_histories(size, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        fuzz::check_round_trip,
+        generate::{assert_generates_valid, rng},
+        read_input, Answer,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    fn evaluate(coefficients: &[i32], x: i32) -> i32 {
+        coefficients.iter().rev().fold(0, |acc, &c| acc * x + c)
+    }
+
+    proptest! {
+        /// A history sampled from a polynomial has to be extrapolated to the polynomial's next values,
+        /// as long as there are enough samples to pin the polynomial down.
+        #[test]
+        fn test_extrapolation_matches_polynomial(
+            coefficients in vec(-5..=5i32, 1..=5),
+            extra_samples in 0..8i32,
+        ) {
+            let samples = coefficients.len() as i32 + extra_samples;
+            let history: Vec<i32> = (0..samples).map(|x| evaluate(&coefficients, x)).collect();
+            prop_assert_eq!(extrapolate_history(&history), Some(evaluate(&coefficients, samples).into()));
+            prop_assert_eq!(extrapolate_history_backwards(&history), Some(evaluate(&coefficients, -1).into()));
+        }
+    }
+
+    #[test]
+    fn test_compute_pyramid() {
+        assert_eq!(
+            compute_diff_pyramid(&[0, 3, 6, 9, 12, 15]),
+            Some(vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_extrapolate_history() {
+        assert_eq!(extrapolate_history(&[0, 3, 6, 9, 12, 15]), Some(18));
+        assert_eq!(extrapolate_history(&[1, 3, 6, 10, 15, 21]), Some(28));
+        assert_eq!(extrapolate_history(&[10, 13, 16, 21, 30, 45]), Some(68));
+        assert_eq!(extrapolate_history(&[-1, -4, -7]), Some(-10));
+        assert_eq!(extrapolate_history(&[5]), Some(5));
+        // Past what 32 bits hold, which the values had to fit in
+        assert_eq!(
+            extrapolate_history(&[0, i32::MAX]),
+            Some(2 * i64::from(i32::MAX))
+        );
+    }
+
+    #[test]
+    fn test_find_extrapolation_sum() {
+        assert_eq!(
+            find_extrapolation_sum(&parse_input(&read_input("example.txt")).unwrap()),
+            Ok(114)
+        );
+        // The differences double with every row, until they don't fit in 64 bits
+        let alternating: Vec<i32> = (0..70)
+            .map(|i| if i % 2 == 0 { i32::MAX } else { i32::MIN })
+            .collect();
+        assert_eq!(
+            find_extrapolation_sum(&[alternating]),
+            Err(SolveError::Overflow("a history's differences".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_extrapolate_history_backwards() {
+        assert_eq!(extrapolate_history_backwards(&[1, 4, 7]), Some(-2));
+        assert_eq!(
+            extrapolate_history_backwards(&[10, 13, 16, 21, 30, 45]),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day9::parse(&read_input("example.txt")).unwrap();
+        assert_eq!(Day9::part_1(&input), Ok(Answer::from(114)));
+        assert_eq!(Day9::part_2(&input), Ok(Answer::from(2)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_input("0 3 6\n1 x 3"),
+            Err(ParseError {
+                line: 2,
+                span: Some(2..3),
+                kind: ParseErrorKind::BadNumber("x".to_string())
+            })
+        );
+        assert_eq!(
+            parse_input("0 3 6\n\n1 2 3"),
+            Err(ParseError {
+                line: 2,
+                span: Some(0..0),
+                kind: ParseErrorKind::EmptyHistory("".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_generates_valid::<Day9>(100);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert!(check_round_trip::<Day9>(read_input("example.txt").as_bytes()).is_some());
+        let generated = Day9::generate(100, &mut rng(1)).unwrap();
+        assert!(check_round_trip::<Day9>(generated.as_bytes()).is_some());
+    }
+}
@@ -0,0 +1,54 @@
+use std::{fs, hint::black_box};
+
+use aoc::{
+    answers::{Answers, Key},
+    days::{day_dir, DAYS},
+};
+use aoc_common::PuzzleInput;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parsing and each part separately, for every day's example and real input, in every year.
+///
+/// Only parts with a recorded answer are solved, which leaves out the ones that fail on an input
+/// (like day 1's part 1 on the example, which has no digits on some lines).
+fn bench_days(c: &mut Criterion) {
+    let answers = Answers::load(&Answers::default_path()).expect("answers.txt should be valid");
+
+    for day in DAYS {
+        for input in ["example.txt", "input.txt"] {
+            let Ok(text) = fs::read_to_string(day_dir(day.year, day.number).join(input)) else {
+                continue;
+            };
+            let text = PuzzleInput::new(&text);
+            let parsed = day
+                .parse(&text)
+                .unwrap_or_else(|err| panic!("{} day {} {input}: {err}", day.year, day.number));
+
+            let name = input.trim_end_matches(".txt");
+            let mut group = c.benchmark_group(format!("{}/day_{}/{name}", day.year, day.number));
+            if input == "input.txt" {
+                group.sample_size(10);
+            }
+
+            group.bench_function("parse", |b| b.iter(|| day.parse(black_box(&text))));
+            for part in [1, 2] {
+                let key = Key {
+                    year: day.year,
+                    day: day.number,
+                    part,
+                    input: input.to_string(),
+                };
+                if answers.get(&key).is_none() {
+                    continue;
+                }
+                group.bench_function(format!("part_{part}"), |b| {
+                    b.iter(|| day.solve(black_box(parsed.as_ref()), part))
+                });
+            }
+            group.finish();
+        }
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,389 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::parse::{parse_lines, ErrorKind, Field, ParseError, Pattern};
+
+use crate::days::workspace_dir;
+
+/// Identifies one answer: a part of a day's puzzle, solved for one of the day's input files.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+pub struct Key {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    /// The input's file name, relative to the day's directory
+    pub input: String,
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {}, part {} ({})",
+            self.year, self.day, self.part, self.input
+        )
+    }
+}
+
+/// What the site said about an answer.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way
+    Wrong,
+}
+
+impl Verdict {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "correct" => Some(Self::Correct),
+            "too-high" => Some(Self::TooHigh),
+            "too-low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::Wrong => "wrong",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wrong => "wrong",
+        };
+        write!(f, "{description}")
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum AnswersErrorKind {
+    MissingFields(String),
+    BadYear(String),
+    BadDay(String),
+    BadPart(String),
+    BadVerdict(String),
+}
+
+impl ErrorKind for AnswersErrorKind {
+    fn token(&self) -> &str {
+        match self {
+            Self::MissingFields(token)
+            | Self::BadYear(token)
+            | Self::BadDay(token)
+            | Self::BadPart(token)
+            | Self::BadVerdict(token) => token,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::MissingFields(_) => {
+                "a line like \"<year> <day> <part> <input> <verdict> <answer>\""
+            }
+            Self::BadYear(_) => "a year",
+            Self::BadDay(_) => "a day number",
+            Self::BadPart(_) => "1 or 2",
+            Self::BadVerdict(_) => "correct, too-high, too-low or wrong",
+        }
+    }
+}
+
+pub type AnswersError = ParseError<AnswersErrorKind>;
+
+const HEADER: &str = "\
+# Confirmed answers, checked by `aoc verify` and added to by `aoc record` and `aoc submit`.
+# Answers the site rejected are kept too, so they're never submitted again.
+# <year> <day> <part> <input> <verdict> <answer>
+";
+
+/// The registry of confirmed and rejected answers, kept in a plain text file so changes show up in review.
+#[derive(PartialEq, Eq, Default, Debug)]
+pub struct Answers {
+    answers: BTreeMap<Key, BTreeMap<String, Verdict>>,
+}
+
+impl Answers {
+    /// The registry lives at the root of the workspace, next to the days.
+    pub fn default_path() -> PathBuf {
+        workspace_dir().join("answers.txt")
+    }
+
+    fn parse_line(line: &str) -> Result<Option<(Key, Verdict, String)>, AnswersError> {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            return Ok(None);
+        }
+        let line = Field {
+            text: trimmed,
+            start: line.len() - line.trim_start().len(),
+        };
+
+        // The answer takes the rest of the line, so it can have spaces in it
+        let [year, day, part, input, verdict, answer] =
+            Pattern::new("{} {} {} {} {} {}").fields(line, AnswersErrorKind::MissingFields)?;
+        let year = year.parse(AnswersErrorKind::BadYear)?;
+        let day = day.parse(AnswersErrorKind::BadDay)?;
+        let part = match part.text {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(part.error(AnswersErrorKind::BadPart)),
+        };
+        let verdict = Verdict::parse(verdict.text)
+            .ok_or_else(|| verdict.error(AnswersErrorKind::BadVerdict))?;
+        let key = Key {
+            year,
+            day,
+            part,
+            input: input.text.to_string(),
+        };
+        Ok(Some((key, verdict, answer.text.to_string())))
+    }
+
+    pub fn parse(s: &str) -> Result<Self, AnswersError> {
+        let mut answers = Self::default();
+        for (key, verdict, answer) in parse_lines(s, Self::parse_line)?.into_iter().flatten() {
+            answers.record_verdict(key, answer, verdict);
+        }
+        Ok(answers)
+    }
+
+    /// Loads the registry from `path`, which doesn't have to exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(format!("Couldn't read {}: {}", path.display(), err)),
+        };
+        Self::parse(&text).map_err(|err| format!("Couldn't parse {}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))
+    }
+
+    /// The confirmed answer for `key`.
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.answers
+            .get(key)?
+            .iter()
+            .find(|(_, &verdict)| verdict == Verdict::Correct)
+            .map(|(answer, _)| answer.as_str())
+    }
+
+    /// Records a confirmed answer, returning the one it replaced.
+    pub fn record(&mut self, key: Key, answer: String) -> Option<String> {
+        let previous = self.get(&key).map(str::to_string);
+        self.record_verdict(key, answer, Verdict::Correct);
+        previous
+    }
+
+    /// Records what the site said about an answer. There's only ever one correct answer.
+    pub fn record_verdict(&mut self, key: Key, answer: String, verdict: Verdict) {
+        let answers = self.answers.entry(key).or_default();
+        if verdict == Verdict::Correct {
+            answers.retain(|_, v| *v != Verdict::Correct);
+        }
+        answers.insert(answer, verdict);
+    }
+
+    /// What's already known about `answer`, without asking the site.
+    /// Besides answers that were already submitted, numbers past a too high or too low answer are known to be wrong.
+    pub fn check(&self, key: &Key, answer: &str) -> Option<Verdict> {
+        let answers = self.answers.get(key)?;
+        if let Some(&verdict) = answers.get(answer) {
+            return Some(verdict);
+        }
+        if answers.values().any(|&v| v == Verdict::Correct) {
+            return Some(Verdict::Wrong);
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+        answers.iter().find_map(|(known, &verdict)| {
+            let known: i128 = known.parse().ok()?;
+            match verdict {
+                Verdict::TooHigh if answer >= known => Some(Verdict::TooHigh),
+                Verdict::TooLow if answer <= known => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    /// Every input of a day that has an answer recorded for it.
+    pub fn inputs(&self, year: u32, day: u32) -> Vec<&str> {
+        let mut inputs: Vec<_> = self
+            .answers
+            .keys()
+            .filter(|key| key.year == year && key.day == day)
+            .map(|key| key.input.as_str())
+            .collect();
+        inputs.sort();
+        inputs.dedup();
+        inputs
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{HEADER}")?;
+        for (key, answers) in &self.answers {
+            for (answer, verdict) in answers {
+                writeln!(
+                    f,
+                    "{} {} {} {} {} {}",
+                    key.year,
+                    key.day,
+                    key.part,
+                    key.input,
+                    verdict.as_str(),
+                    answer
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(day: u32, part: u8, input: &str) -> Key {
+        Key {
+            year: 2023,
+            day,
+            part,
+            input: input.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# comment\n\n2023 7 1 input.txt correct 6440\n2023 7 2 example.txt correct 5905\n\
+             2024 7 1 input.txt correct 12\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(&key(7, 1, "input.txt")), Some("6440"));
+        assert_eq!(answers.get(&key(7, 2, "example.txt")), Some("5905"));
+        assert_eq!(answers.get(&key(7, 2, "input.txt")), None);
+        let next_year = Key {
+            year: 2024,
+            ..key(7, 1, "input.txt")
+        };
+        assert_eq!(answers.get(&next_year), Some("12"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("2023 7 1 input.txt 6440"),
+            Err(ParseError {
+                line: 1,
+                span: Some(0..23),
+                kind: AnswersErrorKind::MissingFields("2023 7 1 input.txt 6440".to_string())
+            })
+        );
+        assert_eq!(
+            Answers::parse("2023 7 1 input.txt correct 1\n2023 7 3 input.txt correct 2"),
+            Err(ParseError {
+                line: 2,
+                span: Some(7..8),
+                kind: AnswersErrorKind::BadPart("3".to_string())
+            })
+        );
+        assert_eq!(
+            Answers::parse("2023 7 1 input.txt maybe 1"),
+            Err(ParseError {
+                line: 1,
+                span: Some(19..24),
+                kind: AnswersErrorKind::BadVerdict("maybe".to_string())
+            })
+        );
+        assert_eq!(
+            Answers::parse("twenty 7 1 input.txt correct 1"),
+            Err(ParseError {
+                line: 1,
+                span: Some(0..6),
+                kind: AnswersErrorKind::BadYear("twenty".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        assert_eq!(
+            answers.record(key(2, 1, "input.txt"), "8".to_string()),
+            None
+        );
+        answers.record(key(1, 2, "input.txt"), "two words".to_string());
+        answers.record_verdict(key(2, 1, "input.txt"), "10".to_string(), Verdict::TooHigh);
+        assert_eq!(
+            answers.record(key(2, 1, "input.txt"), "9".to_string()),
+            Some("8".to_string())
+        );
+
+        let text = answers.to_string();
+        assert!(text.ends_with(
+            "2023 1 2 input.txt correct two words\n\
+             2023 2 1 input.txt too-high 10\n\
+             2023 2 1 input.txt correct 9\n"
+        ));
+        assert_eq!(Answers::parse(&text), Ok(answers));
+    }
+
+    #[test]
+    fn test_inputs() {
+        let answers = Answers::parse(
+            "2023 3 1 example.txt correct 1\n\
+             2023 3 2 example.txt correct 2\n\
+             2023 3 1 input.txt correct 3\n\
+             2023 4 1 other.txt correct 4\n\
+             2024 3 1 other.txt correct 5",
+        )
+        .unwrap();
+        assert_eq!(answers.inputs(2023, 3), vec!["example.txt", "input.txt"]);
+        assert_eq!(answers.inputs(2023, 5), Vec::<&str>::new());
+        assert_eq!(answers.inputs(2024, 3), vec!["other.txt"]);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        let key = key(5, 2, "input.txt");
+        assert_eq!(answers.check(&key, "50"), None);
+
+        answers.record_verdict(key.clone(), "100".to_string(), Verdict::TooHigh);
+        answers.record_verdict(key.clone(), "20".to_string(), Verdict::TooLow);
+        answers.record_verdict(key.clone(), "abc".to_string(), Verdict::Wrong);
+        assert_eq!(answers.check(&key, "abc"), Some(Verdict::Wrong));
+        assert_eq!(answers.check(&key, "100"), Some(Verdict::TooHigh));
+        assert_eq!(answers.check(&key, "150"), Some(Verdict::TooHigh));
+        assert_eq!(answers.check(&key, "5"), Some(Verdict::TooLow));
+        assert_eq!(answers.check(&key, "50"), None);
+        assert_eq!(answers.get(&key), None);
+
+        // Once the correct answer is known, everything else is wrong
+        answers.record(key.clone(), "46".to_string());
+        assert_eq!(answers.check(&key, "46"), Some(Verdict::Correct));
+        assert_eq!(answers.check(&key, "50"), Some(Verdict::Wrong));
+        assert_eq!(answers.get(&key), Some("46"));
+    }
+}
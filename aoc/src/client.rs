@@ -0,0 +1,296 @@
+use std::{fs, path::Path, time::Duration};
+
+use crate::answers::Verdict;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The site asks automated tools to identify themselves.
+const USER_AGENT: &str = "aoc runner for RedSponge/advent-of-code-2023";
+
+/// Talks to the Advent of Code site, or anything serving the same routes under `base_url`.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+/// Whether [`Client::fetch_input`] had to download the input.
+#[derive(PartialEq, Eq, Debug)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+impl Client {
+    /// `session` is the value of the site's session cookie, which identifies whose input to download.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn download_input(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(404, _) => {
+                    format!("Day {day} of {year} isn't unlocked yet ({url})")
+                }
+                ureq::Error::Status(400 | 500, _) => {
+                    format!("The session token was rejected by {url}")
+                }
+                err => format!("Couldn't download {url}: {err}"),
+            })?;
+        response
+            .into_string()
+            .map_err(|err| format!("Couldn't read the response from {url}: {err}"))
+    }
+
+    /// Submits an answer to a part of a day's puzzle.
+    pub fn submit(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<Outcome, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| match err {
+                ureq::Error::Status(400 | 500, _) => {
+                    format!("The session token was rejected by {url}")
+                }
+                err => format!("Couldn't submit to {url}: {err}"),
+            })?;
+        let page = response
+            .into_string()
+            .map_err(|err| format!("Couldn't read the response from {url}: {err}"))?;
+        parse_outcome(&page)
+    }
+
+    /// Downloads a day's input to `path`, unless it's already there.
+    /// Inputs never change, so a cached one is never downloaded again.
+    pub fn fetch_input(&self, year: u32, day: u32, path: &Path) -> Result<Fetched, String> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.download_input(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("Couldn't create {}: {}", dir.display(), err))?;
+        }
+        fs::write(path, input)
+            .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+/// What the site said to a submitted answer.
+#[derive(PartialEq, Eq, Debug)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently, so this one wasn't checked
+    RateLimited(Duration),
+    /// The part was already solved, so this answer wasn't checked
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// The verdict to record for the answer, when it was checked.
+    pub fn verdict(&self) -> Option<Verdict> {
+        match self {
+            Self::Correct => Some(Verdict::Correct),
+            Self::TooHigh => Some(Verdict::TooHigh),
+            Self::TooLow => Some(Verdict::TooLow),
+            Self::Wrong => Some(Verdict::Wrong),
+            Self::RateLimited(_) | Self::AlreadySolved => None,
+        }
+    }
+}
+
+/// Reads a wait like "1m 4s" from "You have 1m 4s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|amount| {
+            let unit = match amount.chars().last()? {
+                'h' => 60 * 60,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            let amount: u64 = amount[..amount.len() - 1].parse().ok()?;
+            Some(amount * unit)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Finds out what happened to a submitted answer from the page the site responded with.
+pub fn parse_outcome(page: &str) -> Result<Outcome, String> {
+    if page.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Wrong)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        parse_wait(page)
+            .map(Outcome::RateLimited)
+            .ok_or_else(|| "Couldn't find how long to wait before answering again".to_string())
+    } else if page.contains("Did you already complete it?") {
+        Ok(Outcome::AlreadySolved)
+    } else {
+        Err("Couldn't understand the response to the answer".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// Serves a single day's input on a local port, counting the requests it gets.
+    fn serve_input(year: u32, day: u32, input: &'static str) -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let authorized = request
+                    .headers()
+                    .iter()
+                    .any(|h| h.field.equiv("Cookie") && h.value == "session=secret");
+                let response = if !authorized {
+                    Response::from_string("Puzzle inputs differ by user.").with_status_code(400)
+                } else if request.url() == format!("/{year}/day/{day}/input") {
+                    Response::from_string(input)
+                } else {
+                    Response::from_string("Not found").with_status_code(404)
+                };
+                request.respond(response).unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, requests) = serve_input(2023, 10, "7-F7-\n.FJ|7\n");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("2023").join("day_10").join("input.txt");
+        let client = Client::new(&base_url, "secret");
+
+        assert_eq!(client.fetch_input(2023, 10, &path), Ok(Fetched::Downloaded));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "7-F7-\n.FJ|7\n");
+        assert_eq!(client.fetch_input(2023, 10, &path), Ok(Fetched::Cached));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _requests) = serve_input(2023, 10, "");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+
+        let err = Client::new(&base_url, "secret")
+            .fetch_input(2023, 11, &path)
+            .unwrap_err();
+        assert!(err.contains("isn't unlocked yet"), "{err}");
+        let err = Client::new(&base_url, "wrong")
+            .fetch_input(2023, 10, &path)
+            .unwrap_err();
+        assert!(err.contains("session token was rejected"), "{err}");
+        // Nothing is cached when the download fails
+        assert!(!path.exists());
+    }
+
+    fn article(text: &str) -> String {
+        format!("<html><body><main><article><p>{text}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let outcome = |text: &str| parse_outcome(&article(text));
+        assert_eq!(
+            outcome("That's the right answer!  You are one gold star closer to restoring snow operations."),
+            Ok(Outcome::Correct)
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+            Ok(Outcome::TooHigh)
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low.  Please wait one minute before trying again."),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(
+            outcome("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+            Ok(Outcome::Wrong)
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait."),
+            Ok(Outcome::RateLimited(Duration::from_secs(64)))
+        );
+        assert_eq!(
+            outcome("You don't seem to be solving the right level.  Did you already complete it?"),
+            Ok(Outcome::AlreadySolved)
+        );
+        assert!(outcome("Something else entirely").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let text = match (request.url(), body.as_str()) {
+                    ("/2023/day/7/answer", "level=2&answer=5905") => "That's the right answer!",
+                    ("/2023/day/7/answer", "level=2&answer=6000") => {
+                        "That's not the right answer; your answer is too high."
+                    }
+                    _ => "Something unexpected",
+                };
+                request
+                    .respond(Response::from_string(article(text)))
+                    .unwrap();
+            }
+        });
+
+        let client = Client::new(&base_url, "secret");
+        assert_eq!(client.submit(2023, 7, 2, "5905"), Ok(Outcome::Correct));
+        assert_eq!(client.submit(2023, 7, 2, "6000"), Ok(Outcome::TooHigh));
+        assert!(client.submit(2023, 7, 1, "5905").is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    any::Any,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{
+    diagnostic::Diagnose, generate::InputRng, visualize::Visualization, PuzzleInput, Solution,
+    SolveResult,
+};
+
+/// The result of parsing a day's input, before it's handed to the parts.
+pub type ParseResult = Result<Box<dyn Any>, Box<dyn Diagnose>>;
+
+/// A day's [`Solution`] with its input type erased, so every day of every year can share the same table.
+pub struct Day {
+    pub year: u32,
+    pub number: u32,
+    parse: fn(&PuzzleInput) -> ParseResult,
+    part_1: fn(&dyn Any) -> SolveResult,
+    part_2: fn(&dyn Any) -> SolveResult,
+    generate: fn(usize, &mut InputRng) -> Option<String>,
+    visualize: fn(&dyn Any) -> Vec<Visualization>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u32, number: u32) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year,
+            number,
+            parse: |s| match S::parse(s) {
+                Ok(input) => Ok(Box::new(input)),
+                Err(err) => Err(Box::new(err)),
+            },
+            part_1: |input| S::part_1(downcast_input::<S>(input)),
+            part_2: |input| S::part_2(downcast_input::<S>(input)),
+            generate: S::generate,
+            visualize: |input| S::visualize(downcast_input::<S>(input)),
+        }
+    }
+
+    pub fn parse(&self, input: &PuzzleInput) -> ParseResult {
+        (self.parse)(input)
+    }
+
+    /// Makes up an input for the day, if it has a generator.
+    pub fn generate(&self, size: usize, rng: &mut InputRng) -> Option<String> {
+        (self.generate)(size, rng)
+    }
+
+    /// Draws a parsed input, in as many formats as the day has drawings for.
+    pub fn visualize(&self, input: &dyn Any) -> Vec<Visualization> {
+        (self.visualize)(input)
+    }
+
+    pub fn solve(&self, input: &dyn Any, part: u8) -> SolveResult {
+        match part {
+            1 => (self.part_1)(input),
+            2 => (self.part_2)(input),
+            _ => panic!("Puzzles only have 2 parts, got {part}"),
+        }
+    }
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("Input should've been parsed by the same day")
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<y2023_day_1::Day1>(2023, 1),
+    Day::new::<y2023_day_2::Day2>(2023, 2),
+    Day::new::<y2023_day_3::Day3>(2023, 3),
+    Day::new::<y2023_day_4::Day4>(2023, 4),
+    Day::new::<y2023_day_5::Day5>(2023, 5),
+    Day::new::<y2023_day_6::Day6>(2023, 6),
+    Day::new::<y2023_day_7::Day7>(2023, 7),
+    Day::new::<y2023_day_8::Day8>(2023, 8),
+    Day::new::<y2023_day_9::Day9>(2023, 9),
+];
+
+/// The days are laid out next to the runner, so their inputs can be found no matter where it's launched from.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Each year's days are kept together, in a directory named after the year.
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    workspace_dir()
+        .join(year.to_string())
+        .join(format!("day_{day}"))
+}
+
+/// The inputs kept in a day's directory `dir`: its input.txt and any named ones (like `inputs/alice.txt`) in its
+/// inputs/ directory. They're named by their path relative to the day's directory.
+pub fn day_inputs(dir: &Path) -> Vec<String> {
+    let mut named: Vec<String> = fs::read_dir(dir.join("inputs"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name.ends_with(".txt"))
+        .map(|name| format!("inputs/{name}"))
+        .collect();
+    named.sort();
+
+    if named.is_empty() || dir.join("input.txt").exists() {
+        named.insert(0, "input.txt".to_string());
+    }
+    named
+}
+
+pub fn find_day(year: u32, number: u32) -> Result<&'static Day, String> {
+    DAYS.iter()
+        .find(|d| d.year == year && d.number == number)
+        .ok_or_else(|| format!("Day {number} of {year} isn't solved yet"))
+}
+
+/// The days of `year` that are solved, in order.
+pub fn year_days(year: u32) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+
+/// The year commands default to when they're not given one: the latest one with a solved day.
+pub fn latest_year() -> u32 {
+    DAYS.iter()
+        .map(|d| d.year)
+        .max()
+        .expect("There's at least one solved day")
+}
@@ -0,0 +1,13 @@
+//! The runner's building blocks, shared by the `aoc` binary and the benchmarks.
+
+pub mod answers;
+pub mod client;
+pub mod days;
+pub mod report;
+pub mod run;
+pub mod scaffold;
+pub mod solve;
+pub mod submit;
+pub mod throttle;
+pub mod verify;
+pub mod watch;
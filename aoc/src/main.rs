@@ -0,0 +1,253 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{alloc::CountingAllocator, generate};
+use clap::{Parser, Subcommand};
+
+use aoc::{
+    answers::{Answers, Key},
+    client::{Client, Fetched, DEFAULT_BASE_URL},
+    days::{self, day_dir, find_day, workspace_dir},
+    run::{run, Format},
+    scaffold,
+    submit::{record, submit},
+    throttle::Throttle,
+    verify::verify,
+    watch,
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle and print the answers
+    Run {
+        /// Defaults to the latest year with a solved day
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u32>,
+        /// Solve every day of the year in turn, carrying on past the ones that fail
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Only solve this part. Both parts are solved by default
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// A file to solve, or - for stdin. Can be given more than once to solve several inputs side by side.
+        /// Defaults to the day's input.txt, along with any named inputs in its inputs/ directory
+        #[arg(long)]
+        input: Vec<PathBuf>,
+        /// Also report the time taken and memory allocated by parsing and each part
+        #[arg(long)]
+        stats: bool,
+        /// How to print the answers. JSON always includes the stats
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Also draw each input into this directory, as text, SVG or Graphviz files depending on the day
+        #[arg(long, value_name = "DIR", conflicts_with = "all")]
+        visualize: Option<PathBuf>,
+    },
+    /// Check the solvers against the recorded answers
+    Verify {
+        /// Only check this year. Every year is checked by default
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only check this day. Defaults to the latest year when no year is given
+        #[arg(long)]
+        day: Option<u32>,
+    },
+    /// Record a confirmed answer in answers.txt
+    Record {
+        /// Defaults to the latest year with a solved day
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The input's file name in the day's directory
+        #[arg(long, default_value = "input.txt")]
+        input: String,
+        /// Defaults to the solver's current answer
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Download a day's input to its input.txt, unless it's already there
+    Fetch {
+        /// Defaults to the latest year with a solved day
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        /// The value of the site's session cookie
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+        #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Create a new day's crate and add it to the runner
+    New {
+        /// Defaults to the latest year with a solved day
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+    },
+    /// Submit the answer for a day's input.txt and record what the site says about it
+    Submit {
+        /// Defaults to the latest year with a solved day
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Defaults to the solver's current answer
+        #[arg(long)]
+        answer: Option<String>,
+        /// The value of the site's session cookie
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+        #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Rerun a day's tests and solution whenever one of its files changes, comparing the answers to the last run's
+    /// and the recorded ones
+    Watch {
+        /// Defaults to the latest year with a solved day
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+    },
+    /// Print a made up input for a day, e.g. to benchmark or stress test it
+    Gen {
+        /// Defaults to the latest year with a solved day
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        /// How big the input is, in a unit that depends on the day (lines, grid width, ...)
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// The same seed always makes the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+fn fetch(year: u32, day: u32, session: &str, base_url: &str) -> Result<(), String> {
+    let path = day_dir(year, day).join("input.txt");
+    match Client::new(base_url, session).fetch_input(year, day, &path)? {
+        Fetched::Cached => println!("{} is already downloaded", path.display()),
+        Fetched::Downloaded => println!("Downloaded {}", path.display()),
+    }
+    Ok(())
+}
+
+fn new(year: u32, day: u32) -> Result<(), String> {
+    scaffold::new_day(&workspace_dir(), year, day)?;
+    println!("Created {year}/day_{day}, with its example test failing until it's solved");
+    Ok(())
+}
+
+fn gen(year: u32, day: u32, size: usize, seed: u64) -> Result<(), String> {
+    let solver = find_day(year, day)?;
+    let input = solver
+        .generate(size, &mut generate::rng(seed))
+        .ok_or_else(|| format!("Day {day} has no input generator"))?;
+    print!("{input}");
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let or_latest = |year: Option<u32>| year.unwrap_or_else(days::latest_year);
+    let result = match cli.command {
+        Command::Run {
+            year,
+            day,
+            all: _,
+            part,
+            input,
+            stats,
+            format,
+            visualize,
+        } => run(
+            or_latest(year),
+            day,
+            part,
+            input,
+            stats,
+            format,
+            visualize.as_deref(),
+        ),
+        Command::Verify { year, day } => verify(year, day),
+        Command::Record {
+            year,
+            day,
+            part,
+            input,
+            answer,
+        } => {
+            let key = Key {
+                year: or_latest(year),
+                day,
+                part,
+                input,
+            };
+            record(&Answers::default_path(), key, answer)
+        }
+        Command::Fetch {
+            year,
+            day,
+            session,
+            base_url,
+        } => fetch(or_latest(year), day, &session, &base_url),
+        Command::New { year, day } => new(or_latest(year), day),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            session,
+            base_url,
+        } => {
+            let key = Key {
+                year: or_latest(year),
+                day,
+                part,
+                input: "input.txt".to_string(),
+            };
+            submit(
+                &Answers::default_path(),
+                &Throttle::new(Throttle::default_path()),
+                &Client::new(&base_url, &session),
+                key,
+                answer,
+            )
+        }
+        Command::Watch { year, day } => watch::watch(or_latest(year), day),
+        Command::Gen {
+            year,
+            day,
+            size,
+            seed,
+        } => gen(or_latest(year), day, size, seed),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::{fmt, time::Duration};
+
+use aoc_common::{alloc::AllocStats, diagnostic::Location, Answer, SolveResult};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+/// Everything `aoc run --format json` found out about a day, for scripts and dashboards to read instead of the text
+/// output. `--all` prints a list of them, one per day.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RunReport {
+    pub year: u32,
+    pub day: u32,
+    pub inputs: Vec<InputReport>,
+}
+
+/// How solving one input went.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InputReport {
+    /// The input's name, as it's shown in the text output
+    pub input: String,
+    pub parse: Timing,
+    /// Why the input couldn't be parsed, in which case none of its parts were solved
+    pub parse_error: Option<ParseErrorReport>,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<AnswerReport>,
+    pub error: Option<String>,
+    #[serde(flatten)]
+    pub timing: Timing,
+}
+
+impl PartReport {
+    pub fn new(part: u8, result: &SolveResult, timing: Timing) -> Self {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(AnswerReport::from(answer)), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+        Self {
+            part,
+            answer,
+            error,
+            timing,
+        }
+    }
+}
+
+/// Numeric answers are JSON numbers, the rest strings.
+#[derive(Serialize, PartialEq, Eq, Debug)]
+#[serde(untagged)]
+pub enum AnswerReport {
+    Number(i128),
+    Text(String),
+}
+
+// Untagged enums can't be read back with 128-bit numbers in them, so the answer is told apart by hand
+impl<'de> Deserialize<'de> for AnswerReport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = AnswerReport;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a number or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Self::Value, E> {
+                Ok(AnswerReport::Number(n.into()))
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Self::Value, E> {
+                Ok(AnswerReport::Number(n.into()))
+            }
+
+            fn visit_i128<E: de::Error>(self, n: i128) -> Result<Self::Value, E> {
+                Ok(AnswerReport::Number(n))
+            }
+
+            fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+                Ok(AnswerReport::Text(text.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+impl fmt::Display for AnswerReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl From<&Answer> for AnswerReport {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Number(n) => Self::Number(*n),
+            Answer::Text(text) => Self::Text(text.clone()),
+        }
+    }
+}
+
+/// How long a phase of solving took, and what it allocated.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Timing {
+    pub time_ns: u64,
+    pub allocations: usize,
+    pub bytes: usize,
+}
+
+impl Timing {
+    pub fn new(time: Duration, allocations: AllocStats) -> Self {
+        Self {
+            time_ns: time.as_nanos().try_into().unwrap_or(u64::MAX),
+            allocations: allocations.allocations,
+            bytes: allocations.bytes,
+        }
+    }
+}
+
+/// A malformed input, with where it went wrong when the parser knows.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct ParseErrorReport {
+    pub file: String,
+    pub message: String,
+    /// 1-based line number
+    pub line: Option<usize>,
+    /// 1-based column, in bytes
+    pub column: Option<usize>,
+    /// The error quoting the offending line, as the text output prints it
+    pub diagnostic: String,
+}
+
+impl ParseErrorReport {
+    pub fn new(
+        file: String,
+        message: String,
+        location: Option<Location>,
+        diagnostic: String,
+    ) -> Self {
+        Self {
+            file,
+            message,
+            line: location.as_ref().map(|l| l.line),
+            column: location.and_then(|l| l.span).map(|span| span.start + 1),
+            diagnostic,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::SolveError;
+
+    use super::*;
+
+    fn timing(time_ns: u64) -> Timing {
+        Timing {
+            time_ns,
+            allocations: 2,
+            bytes: 64,
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let report = RunReport {
+            year: 2023,
+            day: 1,
+            inputs: vec![
+                InputReport {
+                    input: "input.txt".to_string(),
+                    parse: timing(10),
+                    parse_error: None,
+                    parts: vec![
+                        PartReport::new(1, &Ok(Answer::from(54418)), timing(20)),
+                        PartReport::new(
+                            2,
+                            &Err(SolveError::Unsupported("no digits".to_string())),
+                            timing(30),
+                        ),
+                    ],
+                },
+                InputReport {
+                    input: "<stdin>".to_string(),
+                    parse: timing(40),
+                    parse_error: Some(ParseErrorReport::new(
+                        "<stdin>".to_string(),
+                        "expected a number, found \"x\"".to_string(),
+                        Some(Location {
+                            line: 2,
+                            span: Some(3..4),
+                        }),
+                        "error: ...".to_string(),
+                    )),
+                    parts: vec![],
+                },
+            ],
+        };
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            concat!(
+                r#"{"year":2023,"day":1,"inputs":["#,
+                r#"{"input":"input.txt","parse":{"time_ns":10,"allocations":2,"bytes":64},"parse_error":null,"parts":["#,
+                r#"{"part":1,"answer":54418,"error":null,"time_ns":20,"allocations":2,"bytes":64},"#,
+                r#"{"part":2,"answer":null,"error":"Unsupported input: no digits","time_ns":30,"allocations":2,"bytes":64}]},"#,
+                r#"{"input":"<stdin>","parse":{"time_ns":40,"allocations":2,"bytes":64},"parse_error":{"file":"<stdin>","#,
+                r#""message":"expected a number, found \"x\"","line":2,"column":4,"diagnostic":"error: ..."},"parts":[]}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_read_back() {
+        let report = RunReport {
+            year: 2023,
+            day: 7,
+            inputs: vec![InputReport {
+                input: "input.txt".to_string(),
+                parse: timing(10),
+                parse_error: None,
+                parts: vec![
+                    PartReport::new(1, &Ok(Answer::from(6440)), timing(20)),
+                    PartReport::new(2, &Ok(Answer::from("AAA".to_string())), timing(30)),
+                ],
+            }],
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        let read: RunReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            read.inputs[0].parts[0].answer,
+            Some(AnswerReport::Number(6440))
+        );
+        assert_eq!(
+            read.inputs[0].parts[1].answer,
+            Some(AnswerReport::Text("AAA".to_string()))
+        );
+        assert_eq!(read.inputs[0].parts[1].timing, timing(30));
+    }
+
+    #[test]
+    fn test_text_answer() {
+        assert_eq!(
+            serde_json::to_string(&AnswerReport::from(&Answer::from("AAA".to_string()))).unwrap(),
+            r#""AAA""#
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+
+use crate::{
+    days::{self, day_dir, find_day, Day},
+    solve::{choose_inputs, day_report, solve_day, Solved, SolvedPart, Stats},
+};
+
+#[derive(ValueEnum, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+fn print_stats(phases: &[(String, Stats)]) {
+    let width = phases
+        .iter()
+        .map(|(phase, _)| phase.len())
+        .max()
+        .unwrap_or_default();
+    println!();
+    println!(
+        "{:<width$} {:>12} {:>12} {:>12}",
+        "phase", "time", "allocations", "bytes"
+    );
+    for (phase, stats) in phases {
+        println!(
+            "{:<width$} {:>12} {:>12} {:>12}",
+            phase,
+            format!("{:.3?}", stats.time),
+            stats.allocations.allocations,
+            stats.allocations.bytes
+        );
+    }
+}
+
+/// Prints the answers of several inputs side by side, one row per input.
+fn print_answer_table(day: u32, parts: &[u8], solved: &[Solved]) {
+    let width = solved
+        .iter()
+        .map(|s| s.input.name.len())
+        .max()
+        .unwrap_or_default()
+        .max(format!("Day {day}").len());
+    let cells: Vec<Vec<String>> = solved
+        .iter()
+        .map(|s| {
+            parts
+                .iter()
+                .map(|&part| match s.answer(part) {
+                    Some(Ok(answer)) => answer.to_string(),
+                    Some(Err(_)) => "error".to_string(),
+                    None => "parse error".to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    let cell_width = cells
+        .iter()
+        .flatten()
+        .map(String::len)
+        .max()
+        .unwrap_or_default()
+        .max(6);
+
+    let print_row = |first: &str, rest: Vec<String>| {
+        let mut row = format!("{first:<width$}");
+        for cell in rest {
+            row += &format!("  {cell:<cell_width$}");
+        }
+        println!("{}", row.trim_end());
+    };
+    print_row(
+        &format!("Day {day}"),
+        parts.iter().map(|part| format!("part {part}")).collect(),
+    );
+    for (s, row) in solved.iter().zip(cells) {
+        print_row(&s.input.name, row);
+    }
+}
+
+fn print_json(report: &impl serde::Serialize) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|err| format!("Couldn't write the report: {err}"))?;
+    println!("{json}");
+    Ok(())
+}
+
+fn print_answers(
+    day: u32,
+    parts: &[u8],
+    solved: Vec<Solved>,
+    show_stats: bool,
+) -> Result<(), String> {
+    let side_by_side = solved.len() > 1;
+    for s in &solved {
+        let answers = match &s.answers {
+            Ok(answers) => answers,
+            Err(failure) => {
+                eprintln!("{}", failure.diagnostic);
+                if side_by_side {
+                    eprintln!("{}", failure.summary());
+                    continue;
+                }
+                return Err(failure.summary());
+            }
+        };
+        for SolvedPart { part, result, .. } in answers {
+            match result {
+                Ok(_) if side_by_side => {}
+                Ok(answer) => println!("Day {day}, part {part}: {answer}"),
+                Err(err) if side_by_side => {
+                    eprintln!("Day {day}, part {part} ({}): {err}", s.input.name)
+                }
+                Err(err) => eprintln!("Day {day}, part {part}: {err}"),
+            }
+        }
+    }
+    if side_by_side {
+        print_answer_table(day, parts, &solved);
+    }
+
+    if show_stats {
+        let phases: Vec<(String, Stats)> = solved
+            .into_iter()
+            .flat_map(|s| {
+                let name = s.input.name.clone();
+                s.phases().into_iter().map(move |(phase, stats)| {
+                    if side_by_side {
+                        (format!("{name} {phase}"), stats)
+                    } else {
+                        (phase, stats)
+                    }
+                })
+            })
+            .collect();
+        print_stats(&phases);
+    }
+    Ok(())
+}
+
+/// Solves a day for `inputs`, or its own inputs when there are none, and prints the answers.
+/// Without a day, every day of the year is solved.
+pub fn run(
+    year: u32,
+    day: Option<u32>,
+    part: Option<u8>,
+    inputs: Vec<PathBuf>,
+    show_stats: bool,
+    format: Format,
+    visualize_to: Option<&Path>,
+) -> Result<(), String> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let Some(day) = day else {
+        return run_all(year, &parts, show_stats, format);
+    };
+
+    let solver = find_day(year, day)?;
+    let inputs = choose_inputs(&day_dir(year, day), inputs);
+    let solved = solve_day(solver, inputs, &parts, visualize_to)?;
+    let failed: usize = solved.iter().map(|s| s.failed(parts.len())).sum();
+
+    match format {
+        Format::Text => print_answers(day, &parts, solved, show_stats)?,
+        Format::Json => print_json(&day_report(solver, &solved))?,
+    }
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failed} part(s) couldn't be solved"))
+    }
+}
+
+/// Solves every day of `year` for its own inputs. A day that fails doesn't stop the rest from being solved.
+fn run_all(year: u32, parts: &[u8], show_stats: bool, format: Format) -> Result<(), String> {
+    let solvers: Vec<&Day> = days::year_days(year).collect();
+    if solvers.is_empty() {
+        return Err(format!("None of {year}'s days are solved yet"));
+    }
+
+    let mut reports = vec![];
+    let mut failed_days = vec![];
+    for solver in solvers {
+        let inputs = choose_inputs(&day_dir(year, solver.number), vec![]);
+        let result = solve_day(solver, inputs, parts, None).and_then(|solved| {
+            let failed: usize = solved.iter().map(|s| s.failed(parts.len())).sum();
+            match format {
+                Format::Text => print_answers(solver.number, parts, solved, show_stats)?,
+                Format::Json => reports.push(day_report(solver, &solved)),
+            }
+            Ok(failed)
+        });
+        match result {
+            Ok(0) => {}
+            Ok(_) => failed_days.push(solver.number),
+            Err(err) => {
+                eprintln!("error: Day {}: {err}", solver.number);
+                failed_days.push(solver.number);
+            }
+        }
+    }
+
+    if format == Format::Json {
+        print_json(&reports)?;
+    }
+    if failed_days.is_empty() {
+        Ok(())
+    } else {
+        let days: Vec<String> = failed_days.iter().map(u32::to_string).collect();
+        Err(format!(
+            "Some parts of {year}'s day(s) {} couldn't be solved",
+            days.join(", ")
+        ))
+    }
+}
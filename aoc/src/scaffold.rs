@@ -0,0 +1,296 @@
+use std::{fs, path::Path};
+
+const CARGO_TOML: &str = r#"[package]
+name = "y{year}_day_{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_common = { path = "../../aoc_common" }
+"#;
+
+const LIB_RS: &str = r#"use std::convert::Infallible;
+
+use aoc_common::{PuzzleInput, Solution, SolveError, SolveResult};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = String;
+    type Error = Infallible;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(_input: &Self::Input) -> SolveResult {
+        Err(SolveError::Unsupported(
+            "Part 1 isn't solved yet".to_string(),
+        ))
+    }
+
+    fn part_2(_input: &Self::Input) -> SolveResult {
+        Err(SolveError::Unsupported(
+            "Part 2 isn't solved yet".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{read_input, Answer};
+
+    use super::*;
+
+    #[test]
+    fn test_solution() {
+        let input = Day{day}::parse(&read_input("example.txt")).unwrap();
+        // Fails until example.txt is filled in and part 1 is solved
+        assert_eq!(Day{day}::part_1(&input), Ok(Answer::from(0)));
+    }
+}
+"#;
+
+/// A day's year and number, which is the order days are listed in.
+type DayKey = (u32, u32);
+
+/// Inserts `new_line` among the lines that belong to a day (as told by `day_of`), keeping them ordered by year and
+/// day.
+fn insert_day_line(
+    text: &str,
+    day: DayKey,
+    new_line: &str,
+    day_of: impl Fn(&str) -> Option<DayKey>,
+) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let day_lines: Vec<(usize, DayKey)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    if day_lines.iter().any(|&(_, d)| d == day) {
+        return Err(format!("Day {} of {} is already there", day.1, day.0));
+    }
+
+    let index = match day_lines.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => match day_lines.last() {
+            Some(&(i, _)) => i + 1,
+            None => return Err("Couldn't find where the days are listed".to_string()),
+        },
+    };
+
+    let mut lines = lines;
+    lines.insert(index, new_line);
+    let mut result = lines.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+/// `y2023_day_7`, the name of a day's crate
+fn crate_day(name: &str) -> Option<DayKey> {
+    let (year, day) = name.strip_prefix('y')?.split_once("_day_")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `    "2023/day_7",` in the workspace's members
+fn workspace_member_day(line: &str) -> Option<DayKey> {
+    let member = line.trim().strip_prefix('"')?.strip_suffix("\",")?;
+    let (year, day) = member.split_once("/day_")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `y2023_day_7 = { path = "../2023/day_7" }` in the runner's dependencies
+fn dependency_day(line: &str) -> Option<DayKey> {
+    crate_day(line.split_once(' ')?.0)
+}
+
+/// `    Day::new::<y2023_day_7::Day7>(2023, 7),` in the runner's table of days
+fn days_table_day(line: &str) -> Option<DayKey> {
+    crate_day(line.trim().strip_prefix("Day::new::<")?.split_once("::")?.0)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("Couldn't write {}: {}", path.display(), err))
+}
+
+/// Creates the `y{year}_day_{day}` crate in the workspace at `root`, under the year's directory, and wires it into the
+/// runner.
+///
+/// The crate starts with an unsolved [`aoc_common::Solution`], empty input files
+/// and an example test that fails until the day is solved.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<(), String> {
+    let name = format!("y{year}_day_{day}");
+    let member = format!("{year}/day_{day}");
+    let dir = root.join(year.to_string()).join(format!("day_{day}"));
+    // A fetched input.txt may already be there, but nothing else should be
+    if dir.join("Cargo.toml").exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Working out the edits to the existing files first, since they're the likeliest to fail
+    let mut edits = vec![];
+    for (path, new_line, day_of) in [
+        (
+            root.join("Cargo.toml"),
+            format!("    \"{member}\","),
+            workspace_member_day as fn(&str) -> Option<DayKey>,
+        ),
+        (
+            root.join("aoc").join("Cargo.toml"),
+            format!("{name} = {{ path = \"../{member}\" }}"),
+            dependency_day,
+        ),
+        (
+            root.join("aoc").join("src").join("days.rs"),
+            format!("    Day::new::<{name}::Day{day}>({year}, {day}),"),
+            days_table_day,
+        ),
+    ] {
+        let text = fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read {}: {}", path.display(), err))?;
+        let text = insert_day_line(&text, (year, day), &new_line, day_of)
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+        edits.push((path, text));
+    }
+
+    let src = dir.join("src");
+    fs::create_dir_all(&src)
+        .map_err(|err| format!("Couldn't create {}: {}", src.display(), err))?;
+    let day_str = day.to_string();
+    write_file(
+        &dir.join("Cargo.toml"),
+        &CARGO_TOML
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day_str),
+    )?;
+    write_file(&dir.join(".gitignore"), "target/")?;
+    write_file(&src.join("lib.rs"), &LIB_RS.replace("{day}", &day_str))?;
+    write_file(&dir.join("example.txt"), "")?;
+    if !dir.join("input.txt").exists() {
+        write_file(&dir.join("input.txt"), "")?;
+    }
+
+    for (path, text) in edits {
+        write_file(&path, &text)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_day_line() {
+        let members = "members = [\n    \"aoc\",\n    \"2022/day_2\",\n    \"2023/day_1\",\n    \"2023/day_3\",\n]\n";
+        assert_eq!(
+            insert_day_line(members, (2023, 2), "    \"2023/day_2\",", workspace_member_day),
+            Ok("members = [\n    \"aoc\",\n    \"2022/day_2\",\n    \"2023/day_1\",\n    \"2023/day_2\",\n    \
+                \"2023/day_3\",\n]\n"
+                .to_string())
+        );
+        assert_eq!(
+            insert_day_line(members, (2023, 4), "    \"2023/day_4\",", workspace_member_day),
+            Ok("members = [\n    \"aoc\",\n    \"2022/day_2\",\n    \"2023/day_1\",\n    \"2023/day_3\",\n    \
+                \"2023/day_4\",\n]\n"
+                .to_string())
+        );
+        // Days are ordered by year first
+        assert_eq!(
+            insert_day_line(members, (2022, 5), "    \"2022/day_5\",", workspace_member_day),
+            Ok("members = [\n    \"aoc\",\n    \"2022/day_2\",\n    \"2022/day_5\",\n    \"2023/day_1\",\n    \
+                \"2023/day_3\",\n]\n"
+                .to_string())
+        );
+        assert!(insert_day_line(
+            members,
+            (2023, 3),
+            "    \"2023/day_3\",",
+            workspace_member_day
+        )
+        .is_err());
+        assert!(insert_day_line("members = []", (2023, 3), "", workspace_member_day).is_err());
+    }
+
+    #[test]
+    fn test_day_lines() {
+        assert_eq!(
+            workspace_member_day("    \"2023/day_12\","),
+            Some((2023, 12))
+        );
+        assert_eq!(workspace_member_day("    \"aoc_common\","), None);
+        assert_eq!(
+            dependency_day("y2023_day_9 = { path = \"../2023/day_9\" }"),
+            Some((2023, 9))
+        );
+        assert_eq!(dependency_day("clap = \"4\""), None);
+        assert_eq!(
+            days_table_day("    Day::new::<y2023_day_9::Day9>(2023, 9),"),
+            Some((2023, 9))
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day_1\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\ny2023_day_1 = { path = \"../2023/day_1\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "pub const DAYS: &[Day] = &[\n    Day::new::<y2023_day_1::Day1>(2023, 1),\n];\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("2023").join("day_2")).unwrap();
+        fs::write(root.join("2023").join("day_2").join("input.txt"), "fetched").unwrap();
+
+        new_day(root, 2023, 2).unwrap();
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("Cargo.toml").contains("    \"2023/day_1\",\n    \"2023/day_2\",\n"));
+        assert!(read("aoc/Cargo.toml").ends_with("y2023_day_2 = { path = \"../2023/day_2\" }\n"));
+        assert!(read("aoc/src/days.rs").contains("    Day::new::<y2023_day_2::Day2>(2023, 2),\n];"));
+        let cargo_toml = read("2023/day_2/Cargo.toml");
+        assert!(cargo_toml.contains("name = \"y2023_day_2\""));
+        assert!(cargo_toml.contains("path = \"../../aoc_common\""));
+        assert!(read("2023/day_2/src/lib.rs").contains("impl Solution for Day2 {"));
+        assert_eq!(read("2023/day_2/example.txt"), "");
+        // A fetched input is kept
+        assert_eq!(read("2023/day_2/input.txt"), "fetched");
+        assert!(new_day(root, 2023, 2).is_err());
+
+        // The first day of a new year goes after the last year's days
+        new_day(root, 2024, 1).unwrap();
+        assert!(read("Cargo.toml").ends_with("    \"2023/day_2\",\n    \"2024/day_1\",\n]\n"));
+        assert!(read("2024/day_1/src/lib.rs").contains("impl Solution for Day1 {"));
+    }
+
+    #[test]
+    fn test_lib_rs_is_formatted() {
+        // A two digit day makes the longest lines
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        fs::write(&path, LIB_RS.replace("{day}", "25")).unwrap();
+        let status = Command::new("rustfmt")
+            .args(["--check", "--edition", "2021"])
+            .arg(&path)
+            .status()
+            .unwrap();
+        assert!(status.success(), "The generated lib.rs isn't formatted");
+    }
+}
@@ -0,0 +1,375 @@
+use std::{
+    any::Any,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    alloc::{count_allocations, AllocStats},
+    diagnostic::{Diagnose, Location},
+    Diagnostic, PuzzleInput, SolveResult,
+};
+
+use crate::{
+    days::{day_inputs, Day},
+    report::{InputReport, ParseErrorReport, PartReport, RunReport, Timing},
+};
+
+/// An input to solve, read from a file or stdin.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Input {
+    /// How the input is shown in reports
+    pub name: String,
+    /// Stdin is read when there's no path
+    path: Option<PathBuf>,
+}
+
+impl Input {
+    pub fn file(name: String, path: PathBuf) -> Self {
+        Self {
+            name,
+            path: Some(path),
+        }
+    }
+
+    /// `-` stands for stdin, like in most command line tools.
+    pub fn from_arg(arg: PathBuf) -> Self {
+        if arg.as_os_str() == "-" {
+            Self {
+                name: "<stdin>".to_string(),
+                path: None,
+            }
+        } else {
+            Self::file(arg.display().to_string(), arg)
+        }
+    }
+
+    /// One of a day's own inputs, named by its path relative to the day's directory `dir`.
+    pub fn in_day(dir: &Path, name: &str) -> Self {
+        Self::file(name.to_string(), dir.join(name))
+    }
+
+    fn read(&self) -> Result<PuzzleInput, String> {
+        let text = match &self.path {
+            Some(path) => fs::read_to_string(path)
+                .map_err(|err| format!("Couldn't read {}: {}", path.display(), err)),
+            None => {
+                io::read_to_string(io::stdin()).map_err(|err| format!("Couldn't read stdin: {err}"))
+            }
+        }?;
+        Ok(PuzzleInput::new(&text))
+    }
+
+    /// Describes why the input couldn't be parsed.
+    /// The diagnostic quotes the normalized input, which is what the spans point into.
+    fn parse_failure(&self, text: &PuzzleInput, err: &dyn Diagnose) -> ParseFailure {
+        let file = match &self.path {
+            Some(path) => path.display().to_string(),
+            None => self.name.clone(),
+        };
+        ParseFailure {
+            diagnostic: Diagnostic::new(&file, text, err).to_string(),
+            message: err.message(),
+            location: err.location(),
+            file,
+        }
+    }
+
+    /// Reads and parses the input, printing a diagnostic when it's malformed.
+    pub fn load(&self, solver: &Day) -> Result<Box<dyn Any>, String> {
+        let text = self.read()?;
+        solver.parse(&text).map_err(|err| {
+            let failure = self.parse_failure(&text, err.as_ref());
+            eprintln!("{}", failure.diagnostic);
+            failure.summary()
+        })
+    }
+}
+
+/// The inputs to solve a day for: the ones given on the command line, or else the day's own ones in `dir`.
+pub fn choose_inputs(dir: &Path, args: Vec<PathBuf>) -> Vec<Input> {
+    if args.is_empty() {
+        day_inputs(dir)
+            .iter()
+            .map(|name| Input::in_day(dir, name))
+            .collect()
+    } else {
+        args.into_iter().map(Input::from_arg).collect()
+    }
+}
+
+/// Why an input couldn't be parsed.
+pub struct ParseFailure {
+    file: String,
+    message: String,
+    location: Option<Location>,
+    /// The error quoting the offending line
+    pub diagnostic: String,
+}
+
+impl ParseFailure {
+    pub fn summary(&self) -> String {
+        format!("Couldn't parse {}", self.file)
+    }
+}
+
+/// How long a phase of solving took, and what it allocated.
+pub struct Stats {
+    pub time: Duration,
+    pub allocations: AllocStats,
+}
+
+impl Stats {
+    fn timing(&self) -> Timing {
+        Timing::new(self.time, self.allocations)
+    }
+}
+
+/// Allocations are only counted when the binary has [`aoc_common::alloc::CountingAllocator`] as its allocator.
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Stats) {
+    let start = Instant::now();
+    let (result, allocations) = count_allocations(f);
+    let stats = Stats {
+        time: start.elapsed(),
+        allocations,
+    };
+    (result, stats)
+}
+
+/// What solving one part came to.
+pub struct SolvedPart {
+    pub part: u8,
+    pub result: SolveResult,
+    pub stats: Stats,
+}
+
+/// What solving the parts of one input came to.
+pub struct Solved {
+    pub input: Input,
+    parse: Stats,
+    /// The answer to each part, unless the input couldn't be parsed
+    pub answers: Result<Vec<SolvedPart>, ParseFailure>,
+}
+
+impl Solved {
+    pub fn answer(&self, part: u8) -> Option<&SolveResult> {
+        let answers = self.answers.as_ref().ok()?;
+        answers.iter().find(|p| p.part == part).map(|p| &p.result)
+    }
+
+    /// The number of parts that weren't solved, out of `parts`.
+    pub fn failed(&self, parts: usize) -> usize {
+        match &self.answers {
+            Ok(answers) => answers.iter().filter(|p| p.result.is_err()).count(),
+            Err(_) => parts,
+        }
+    }
+
+    pub fn phases(self) -> Vec<(String, Stats)> {
+        let mut phases = vec![("parse".to_string(), self.parse)];
+        for part in self.answers.into_iter().flatten() {
+            phases.push((format!("part {}", part.part), part.stats));
+        }
+        phases
+    }
+
+    fn report(&self) -> InputReport {
+        let (parse_error, parts) = match &self.answers {
+            Ok(answers) => {
+                let parts = answers
+                    .iter()
+                    .map(|p| PartReport::new(p.part, &p.result, p.stats.timing()))
+                    .collect();
+                (None, parts)
+            }
+            Err(failure) => {
+                let report = ParseErrorReport::new(
+                    failure.file.clone(),
+                    failure.message.clone(),
+                    failure.location.clone(),
+                    failure.diagnostic.clone(),
+                );
+                (Some(report), vec![])
+            }
+        };
+        InputReport {
+            input: self.input.name.clone(),
+            parse: self.parse.timing(),
+            parse_error,
+            parts,
+        }
+    }
+}
+
+/// Writes the day's drawings of an input to `dir`, named after the day and the input.
+/// A day with nothing to draw only gets a warning, so it's still solved like without `--visualize`.
+fn visualize(solver: &Day, input: &Input, parsed: &dyn Any, dir: &Path) -> Result<(), String> {
+    let visualizations = solver.visualize(parsed);
+    if visualizations.is_empty() {
+        eprintln!("warning: Day {} has nothing to visualize", solver.number);
+        return Ok(());
+    }
+    fs::create_dir_all(dir).map_err(|err| format!("Couldn't create {}: {}", dir.display(), err))?;
+    let stem = Path::new(&input.name)
+        .file_stem()
+        .map_or(input.name.clone(), |stem| {
+            stem.to_string_lossy().into_owned()
+        });
+    let name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    for visualization in visualizations {
+        let path = dir.join(format!(
+            "{}_day_{}_{}.{}",
+            solver.year,
+            solver.number,
+            name.trim_matches('_'),
+            visualization.format
+        ));
+        fs::write(&path, visualization.contents)
+            .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))?;
+        // Not on stdout, which may be a JSON report
+        eprintln!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn solve_input(
+    solver: &Day,
+    input: Input,
+    parts: &[u8],
+    visualize_to: Option<&Path>,
+) -> Result<Solved, String> {
+    let text = input.read()?;
+    let (parsed, parse) = measure(|| solver.parse(&text));
+    if let (Ok(parsed), Some(dir)) = (&parsed, visualize_to) {
+        visualize(solver, &input, parsed.as_ref(), dir)?;
+    }
+    let answers = match parsed {
+        Ok(parsed) => Ok(parts
+            .iter()
+            .map(|&part| {
+                let (result, stats) = measure(|| solver.solve(parsed.as_ref(), part));
+                SolvedPart {
+                    part,
+                    result,
+                    stats,
+                }
+            })
+            .collect()),
+        Err(err) => Err(input.parse_failure(&text, err.as_ref())),
+    };
+    Ok(Solved {
+        input,
+        parse,
+        answers,
+    })
+}
+
+/// Solves `parts` of each input, optionally drawing the inputs into `visualize_to` as well.
+/// Inputs that can't be parsed are reported in what they came to, only the ones that can't be read fail.
+pub fn solve_day(
+    solver: &Day,
+    inputs: Vec<Input>,
+    parts: &[u8],
+    visualize_to: Option<&Path>,
+) -> Result<Vec<Solved>, String> {
+    inputs
+        .into_iter()
+        .map(|input| solve_input(solver, input, parts, visualize_to))
+        .collect()
+}
+
+pub fn day_report(solver: &Day, solved: &[Solved]) -> RunReport {
+    RunReport {
+        year: solver.year,
+        day: solver.number,
+        inputs: solved.iter().map(Solved::report).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Answer;
+
+    use crate::days::find_day;
+
+    use super::*;
+
+    #[test]
+    fn test_choose_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::write(dir.join("inputs").join("bob.txt"), "").unwrap();
+        fs::write(dir.join("inputs").join("alice.txt"), "").unwrap();
+        fs::write(dir.join("inputs").join("notes.md"), "").unwrap();
+
+        // Without an input.txt, the named inputs are enough
+        assert_eq!(
+            choose_inputs(dir, vec![]),
+            vec![
+                Input::file("inputs/alice.txt".to_string(), dir.join("inputs/alice.txt")),
+                Input::file("inputs/bob.txt".to_string(), dir.join("inputs/bob.txt")),
+            ]
+        );
+        fs::write(dir.join("input.txt"), "").unwrap();
+        assert_eq!(
+            choose_inputs(dir, vec![])
+                .into_iter()
+                .map(|input| input.name)
+                .collect::<Vec<_>>(),
+            vec!["input.txt", "inputs/alice.txt", "inputs/bob.txt"]
+        );
+
+        // Inputs given on the command line replace the day's own ones
+        assert_eq!(
+            choose_inputs(dir, vec![PathBuf::from("other.txt"), PathBuf::from("-")]),
+            vec![
+                Input::file("other.txt".to_string(), PathBuf::from("other.txt")),
+                Input {
+                    name: "<stdin>".to_string(),
+                    path: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_choose_inputs_without_any() {
+        // Solving then fails on the missing input.txt, which says what's wrong
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            choose_inputs(dir.path(), vec![]),
+            vec![Input::in_day(dir.path(), "input.txt")]
+        );
+    }
+
+    #[test]
+    fn test_solve_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        fs::write(dir.join("input.txt"), "32T3K 765\nT55J5 684\n").unwrap();
+        fs::write(dir.join("bad.txt"), "32T3K\n").unwrap();
+        let solver = find_day(2023, 7).unwrap();
+        let inputs = vec![
+            Input::in_day(dir, "input.txt"),
+            Input::in_day(dir, "bad.txt"),
+        ];
+
+        let solved = solve_day(solver, inputs, &[1, 2], None).unwrap();
+        assert_eq!(solved[0].answer(1), Some(&Ok(Answer::from(2133usize))));
+        assert_eq!(solved[0].failed(2), 0);
+        assert!(solved[1].answer(1).is_none());
+        assert_eq!(solved[1].failed(2), 2);
+
+        let report = day_report(solver, &solved);
+        assert_eq!(report.inputs.len(), 2);
+        assert!(report.inputs[1].parse_error.is_some());
+
+        let missing = vec![Input::in_day(dir, "missing.txt")];
+        assert!(solve_day(solver, missing, &[1], None).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use std::{path::Path, time::Duration};
+
+use crate::{
+    answers::{Answers, Key, Verdict},
+    client::{Client, Outcome},
+    days::{day_dir, find_day},
+    solve::Input,
+    throttle::Throttle,
+};
+
+/// The site makes you wait at least a minute after a wrong answer.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// The solver's current answer for one of the day's own inputs, for when no answer is given.
+fn solve_answer(key: &Key) -> Result<String, String> {
+    let Key {
+        year,
+        day,
+        part,
+        input,
+    } = key;
+    let solver = find_day(*year, *day)?;
+    let parsed = Input::in_day(&day_dir(*year, *day), input).load(solver)?;
+    let answer = solver
+        .solve(parsed.as_ref(), *part)
+        .map_err(|err| format!("Day {day}, part {part}: {err}"))?;
+    Ok(answer.to_string())
+}
+
+/// Records a confirmed answer in the registry at `path`. Without one, the solver's current answer is recorded.
+pub fn record(path: &Path, key: Key, answer: Option<String>) -> Result<(), String> {
+    let mut answers = Answers::load(path)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve_answer(&key)?,
+    };
+
+    match answers.record(key.clone(), answer.clone()) {
+        Some(previous) if previous != answer => {
+            println!("{key}: recorded {answer}, replacing {previous}")
+        }
+        _ => println!("{key}: recorded {answer}"),
+    }
+    answers.save(path)
+}
+
+/// Submits an answer, unless the registry at `answers_path` already knows it to be right or wrong, and records what
+/// the site says about it. Without an answer, the solver's current one is submitted.
+pub fn submit(
+    answers_path: &Path,
+    throttle: &Throttle,
+    client: &Client,
+    key: Key,
+    answer: Option<String>,
+) -> Result<(), String> {
+    if let Some(left) = throttle.wait_left() {
+        return Err(format!(
+            "The site won't accept another answer for {}s",
+            left.as_secs()
+        ));
+    }
+
+    let mut answers = Answers::load(answers_path)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve_answer(&key)?,
+    };
+    match answers.check(&key, &answer) {
+        Some(Verdict::Correct) => {
+            println!("{key}: {answer} is already known to be correct");
+            return Ok(());
+        }
+        Some(verdict) => {
+            return Err(format!(
+                "{key}: {answer} is known to be {verdict}, not submitting it again"
+            ))
+        }
+        None => {}
+    }
+
+    let outcome = client.submit(key.year, key.day, key.part, &answer)?;
+    if let Some(verdict) = outcome.verdict() {
+        answers.record_verdict(key.clone(), answer.clone(), verdict);
+        answers.save(answers_path)?;
+    }
+    match outcome {
+        Outcome::Correct => {
+            println!("{key}: {answer} is correct!");
+            Ok(())
+        }
+        Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong => {
+            throttle.wait(WRONG_ANSWER_WAIT)?;
+            let verdict = outcome.verdict().expect("Wrong answers have a verdict");
+            Err(format!("{key}: {answer} is {verdict}"))
+        }
+        Outcome::RateLimited(wait) => {
+            throttle.wait(wait)?;
+            Err(format!(
+                "Answered too recently, the site will accept another answer in {}s",
+                wait.as_secs()
+            ))
+        }
+        Outcome::AlreadySolved => {
+            println!("{key} is already solved, use `aoc record` to keep its answer");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    fn key(part: u8) -> Key {
+        Key {
+            year: 2023,
+            day: 7,
+            part,
+            input: "input.txt".to_string(),
+        }
+    }
+
+    /// Answers submissions for day 7 of 2023 on a local port like the site would, counting them.
+    fn serve_answers() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let submissions = Arc::new(AtomicUsize::new(0));
+        let counter = submissions.clone();
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let text = match body.as_str() {
+                    "level=2&answer=5905" => "That's the right answer!",
+                    "level=2&answer=6000" => {
+                        "That's not the right answer; your answer is too high."
+                    }
+                    _ => "You gave an answer too recently; you have to wait after submitting an answer \
+                          before trying again.  You have 1m 4s left to wait.",
+                };
+                let page = format!("<main><article><p>{text}</p></article></main>");
+                request.respond(Response::from_string(page)).unwrap();
+            }
+        });
+        (base_url, submissions)
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, submissions) = serve_answers();
+        let client = Client::new(&base_url, "secret");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.txt");
+        let throttle = |name: &str| Throttle::new(dir.path().join(name));
+        let submitted = || submissions.load(Ordering::SeqCst);
+        let registry = || Answers::load(&path).unwrap();
+
+        // A wrong answer is recorded, and holds off the next one
+        let err = submit(&path, &throttle("a"), &client, key(2), Some("6000".into()));
+        assert_eq!(
+            err,
+            Err("2023 day 7, part 2 (input.txt): 6000 is too high".into())
+        );
+        assert_eq!(registry().check(&key(2), "6000"), Some(Verdict::TooHigh));
+        assert!(throttle("a").wait_left().is_some());
+        assert!(submit(&path, &throttle("a"), &client, key(2), Some("1".into())).is_err());
+        assert_eq!(submitted(), 1);
+
+        // Answers past a too high one aren't submitted
+        let err = submit(&path, &throttle("b"), &client, key(2), Some("6500".into()));
+        assert!(err.unwrap_err().contains("known to be too high"));
+        assert_eq!(submitted(), 1);
+
+        assert_eq!(
+            submit(&path, &throttle("b"), &client, key(2), Some("5905".into())),
+            Ok(())
+        );
+        assert_eq!(registry().get(&key(2)), Some("5905"));
+        assert_eq!(registry().check(&key(2), "6000"), Some(Verdict::TooHigh));
+        assert!(throttle("b").wait_left().is_none());
+        assert_eq!(submitted(), 2);
+
+        // Once the right answer is known, nothing else is submitted
+        assert_eq!(
+            submit(&path, &throttle("b"), &client, key(2), Some("5905".into())),
+            Ok(())
+        );
+        assert!(submit(&path, &throttle("b"), &client, key(2), Some("4000".into())).is_err());
+        assert_eq!(submitted(), 2);
+
+        // The site saying to wait doesn't say anything about the answer
+        let err = submit(&path, &throttle("c"), &client, key(1), Some("6440".into()));
+        assert!(err.unwrap_err().contains("in 64s"));
+        assert_eq!(registry().check(&key(1), "6440"), None);
+        assert!(throttle("c").wait_left().is_some());
+        assert_eq!(submitted(), 3);
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.txt");
+        fs::write(&path, "2023 7 1 input.txt too-low 10\n").unwrap();
+
+        record(&path, key(1), Some("6440".into())).unwrap();
+        record(&path, key(1), Some("6441".into())).unwrap();
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(&key(1)), Some("6441"));
+        assert_eq!(answers.check(&key(1), "6440"), Some(Verdict::Wrong));
+        assert_eq!(answers.check(&key(1), "10"), Some(Verdict::TooLow));
+    }
+}
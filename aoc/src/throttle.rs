@@ -0,0 +1,61 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::days::workspace_dir;
+
+/// The site makes you wait between answers, so the time it'll accept the next one is remembered across runs.
+pub struct Throttle {
+    path: PathBuf,
+}
+
+impl Throttle {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// The throttle lives at the root of the workspace, next to the answers.
+    pub fn default_path() -> PathBuf {
+        workspace_dir().join(".submit-throttle")
+    }
+
+    fn now() -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("The clock shouldn't be before 1970")
+    }
+
+    /// How long until the site accepts another answer, if it won't yet.
+    pub fn wait_left(&self) -> Option<Duration> {
+        let until = fs::read_to_string(&self.path).ok()?;
+        let until = Duration::from_secs(until.trim().parse().ok()?);
+        until
+            .checked_sub(Self::now())
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Holds off answering for `duration` from now.
+    pub fn wait(&self, duration: Duration) -> Result<(), String> {
+        let until = (Self::now() + duration).as_secs_f64().ceil();
+        fs::write(&self.path, until.to_string())
+            .map_err(|err| format!("Couldn't write {}: {}", self.path.display(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle() {
+        let dir = tempfile::tempdir().unwrap();
+        let throttle = Throttle::new(dir.path().join("throttle"));
+        assert_eq!(throttle.wait_left(), None);
+
+        throttle.wait(Duration::from_secs(60)).unwrap();
+        let left = throttle.wait_left().unwrap();
+        assert!(left > Duration::from_secs(58) && left <= Duration::from_secs(61));
+    }
+}
@@ -0,0 +1,215 @@
+use std::{fmt, path::Path};
+
+use crate::{
+    answers::{Answers, Key},
+    days::{self, day_dir, day_inputs, find_day, Day, DAYS},
+    solve::Input,
+};
+
+/// How the solver's answer for a part compares to the recorded one.
+#[derive(PartialEq, Eq, Debug)]
+pub enum Check {
+    Pass,
+    /// The solver's answer, or why it has none
+    Fail {
+        expected: String,
+        got: Result<String, String>,
+    },
+    /// There's no answer recorded for the part
+    Missing,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail {
+                expected,
+                got: Ok(answer),
+            } => write!(f, "FAIL, expected {expected}, got {answer}"),
+            Self::Fail {
+                expected,
+                got: Err(err),
+            } => write!(f, "FAIL, expected {expected}, got error: {err}"),
+            Self::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// Checks the solver against the answers recorded for its inputs, which are in the day's directory `dir`.
+/// Both parts of every input with a recorded answer or in the directory are checked, in the order of the inputs.
+/// Parts without a recorded answer aren't solved, only reported as missing.
+pub fn verify_day(dir: &Path, answers: &Answers, solver: &Day) -> Vec<(Key, Check)> {
+    let day_inputs = day_inputs(dir);
+    let mut inputs = answers.inputs(solver.year, solver.number);
+    inputs.extend(day_inputs.iter().map(String::as_str));
+    inputs.sort();
+    inputs.dedup();
+
+    let mut checks = vec![];
+    for input in inputs {
+        let keys = [1, 2].map(|part| Key {
+            year: solver.year,
+            day: solver.number,
+            part,
+            input: input.to_string(),
+        });
+        let expected = keys.each_ref().map(|key| answers.get(key));
+        if expected.iter().all(Option::is_none) {
+            checks.extend(keys.map(|key| (key, Check::Missing)));
+            continue;
+        }
+
+        let parsed = Input::in_day(dir, input).load(solver);
+        for (key, expected) in keys.into_iter().zip(expected) {
+            let Some(expected) = expected else {
+                checks.push((key, Check::Missing));
+                continue;
+            };
+            let got = match &parsed {
+                Ok(parsed) => solver
+                    .solve(parsed.as_ref(), key.part)
+                    .map(|answer| answer.to_string())
+                    .map_err(|err| err.to_string()),
+                Err(err) => Err(err.clone()),
+            };
+            let check = match got {
+                Ok(answer) if answer == expected => Check::Pass,
+                got => Check::Fail {
+                    expected: expected.to_string(),
+                    got,
+                },
+            };
+            checks.push((key, check));
+        }
+    }
+    checks
+}
+
+/// Checks every solver against the answers recorded for its inputs, or only the ones of `year` or `day`.
+pub fn verify(year: Option<u32>, day: Option<u32>) -> Result<(), String> {
+    let answers = Answers::load(&Answers::default_path())?;
+    let days = match (year, day) {
+        (_, Some(day)) => vec![find_day(year.unwrap_or_else(days::latest_year), day)?],
+        (Some(year), None) => days::year_days(year).collect(),
+        (None, None) => DAYS.iter().collect(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for solver in days {
+        let dir = day_dir(solver.year, solver.number);
+        for (key, check) in verify_day(&dir, &answers, solver) {
+            println!("{key}: {check}");
+            match check {
+                Check::Pass => passed += 1,
+                Check::Fail { .. } => failed += 1,
+                Check::Missing => missing += 1,
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failed} answer(s) didn't match"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn key(part: u8, input: &str) -> Key {
+        Key {
+            year: 2023,
+            day: 7,
+            part,
+            input: input.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_verify_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::write(dir.join("input.txt"), "32T3K 765\nT55J5 684\n").unwrap();
+        fs::write(dir.join("inputs").join("bad.txt"), "32T3K\n").unwrap();
+        fs::write(dir.join("inputs").join("new.txt"), "32T3K 765\n").unwrap();
+        let answers = Answers::parse(
+            "2023 7 1 input.txt correct 2133\n\
+             2023 7 2 input.txt correct 1\n\
+             2023 7 2 input.txt too-high 3000\n\
+             2023 7 1 inputs/bad.txt correct 5\n\
+             2023 7 1 gone.txt correct 6440\n\
+             2023 8 1 input.txt correct 6\n",
+        )
+        .unwrap();
+
+        let checks = verify_day(dir, &answers, find_day(2023, 7).unwrap());
+        let keys: Vec<&Key> = checks.iter().map(|(key, _)| key).collect();
+        assert_eq!(
+            keys,
+            [
+                (1, "gone.txt"),
+                (2, "gone.txt"),
+                (1, "input.txt"),
+                (2, "input.txt"),
+                (1, "inputs/bad.txt"),
+                (2, "inputs/bad.txt"),
+                (1, "inputs/new.txt"),
+                (2, "inputs/new.txt"),
+            ]
+            .map(|(part, input)| key(part, input))
+            .iter()
+            .collect::<Vec<_>>()
+        );
+
+        // A recorded input that isn't there any more can't be read
+        assert!(
+            matches!(&checks[0].1, Check::Fail { expected, got: Err(err) } if expected == "6440" && err.contains("Couldn't read")),
+            "{:?}",
+            checks[0].1
+        );
+        assert_eq!(checks[1].1, Check::Missing);
+        assert_eq!(checks[2].1, Check::Pass);
+        // Only the correct answer counts, not the rejected ones
+        assert_eq!(
+            checks[3].1,
+            Check::Fail {
+                expected: "1".to_string(),
+                got: Ok("2133".to_string())
+            }
+        );
+        assert!(
+            matches!(&checks[4].1, Check::Fail { got: Err(err), .. } if err.contains("Couldn't parse")),
+            "{:?}",
+            checks[4].1
+        );
+        assert_eq!(checks[5].1, Check::Missing);
+        // Inputs without any answers aren't solved
+        assert_eq!(checks[6].1, Check::Missing);
+        assert_eq!(checks[7].1, Check::Missing);
+    }
+
+    #[test]
+    fn test_check_display() {
+        assert_eq!(Check::Pass.to_string(), "pass");
+        assert_eq!(Check::Missing.to_string(), "missing");
+        let fail = |got| Check::Fail {
+            expected: "6440".to_string(),
+            got,
+        };
+        assert_eq!(
+            fail(Ok("6441".to_string())).to_string(),
+            "FAIL, expected 6440, got 6441"
+        );
+        assert_eq!(
+            fail(Err("no hands".to_string())).to_string(),
+            "FAIL, expected 6440, got error: no hands"
+        );
+    }
+}
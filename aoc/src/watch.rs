@@ -0,0 +1,373 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answers::{Answers, Key, Verdict},
+    days::{day_dir, workspace_dir},
+    report::RunReport,
+};
+
+/// How often the day's files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// When the files were last changed, for every file in a day's directory: its sources, example and inputs.
+#[derive(PartialEq, Eq, Default, Debug)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    /// Build output and the hidden and backup files editors keep next to the ones they're editing are left out, so
+    /// only saving a file counts as a change.
+    pub fn take(dir: &Path) -> Self {
+        let mut files = BTreeMap::new();
+        let mut dirs = vec![dir.to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(&dir).into_iter().flatten().flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name == "target" || name.starts_with('.') || name.ends_with('~') {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    dirs.push(entry.path());
+                } else if let Ok(modified) = metadata.modified() {
+                    files.insert(entry.path(), modified);
+                }
+            }
+        }
+        Self(files)
+    }
+
+    /// The files that were added, changed or removed since `earlier`.
+    pub fn changes(&self, earlier: &Self) -> Vec<PathBuf> {
+        let changed = self
+            .0
+            .iter()
+            .filter(|&(path, modified)| earlier.0.get(path) != Some(modified));
+        let removed = earlier
+            .0
+            .iter()
+            .filter(|&(path, _)| !self.0.contains_key(path));
+        changed
+            .chain(removed)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// What each part came to on one run, by input and part: the answer, or why there isn't one.
+pub type RunAnswers = BTreeMap<(String, u8), Result<String, String>>;
+
+pub fn run_answers(report: &RunReport) -> RunAnswers {
+    let mut answers = RunAnswers::new();
+    for input in &report.inputs {
+        if let Some(err) = &input.parse_error {
+            for part in [1, 2] {
+                answers.insert((input.input.clone(), part), Err(err.message.clone()));
+            }
+        }
+        for part in &input.parts {
+            let answer = match (&part.answer, &part.error) {
+                (Some(answer), _) => Ok(answer.to_string()),
+                (None, Some(err)) => Err(err.clone()),
+                (None, None) => Err("no answer".to_string()),
+            };
+            answers.insert((input.input.clone(), part.part), answer);
+        }
+    }
+    answers
+}
+
+/// One line per answer, saying how it compares to the last run's and to what the registry knows about it.
+pub fn describe_answers(
+    year: u32,
+    day: u32,
+    answers: &RunAnswers,
+    previous: Option<&RunAnswers>,
+    registry: &Answers,
+) -> Vec<String> {
+    answers
+        .iter()
+        .map(|((input, part), answer)| {
+            let mut notes = vec![];
+            if let Some(previous) = previous {
+                notes.push(match previous.get(&(input.clone(), *part)) {
+                    Some(before) if before == answer => "unchanged".to_string(),
+                    Some(Ok(before)) => format!("was {before}"),
+                    Some(Err(_)) => "was an error".to_string(),
+                    None => "new".to_string(),
+                });
+            }
+
+            let key = Key {
+                year,
+                day,
+                part: *part,
+                input: input.clone(),
+            };
+            let expected = registry.get(&key);
+            let verdict = answer.as_ref().ok().and_then(|a| registry.check(&key, a));
+            match (verdict, expected) {
+                (Some(Verdict::Correct), _) => notes.push("correct".to_string()),
+                (Some(verdict), Some(expected)) => {
+                    notes.push(format!("{verdict}, expected {expected}"))
+                }
+                (Some(verdict), None) => notes.push(verdict.to_string()),
+                (None, Some(expected)) => notes.push(format!("expected {expected}")),
+                (None, None) => {}
+            }
+
+            let answer = match answer {
+                Ok(answer) => answer.clone(),
+                Err(err) => format!("error: {err}"),
+            };
+            let mut line = format!("{input}, part {part}: {answer}");
+            if !notes.is_empty() {
+                line += &format!(" ({})", notes.join("; "));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Runs cargo in the workspace. Its output goes straight to the terminal, apart from what `stdout` captures.
+fn cargo(args: &[&str], stdout: Stdio) -> Result<std::process::Output, String> {
+    Command::new("cargo")
+        .args(args)
+        .current_dir(workspace_dir())
+        .stdout(stdout)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| format!("Couldn't run cargo: {err}"))
+}
+
+/// Rebuilds the day and solves its inputs, through cargo so the latest sources are the ones that run.
+fn solve(year: u32, day: u32) -> Result<RunReport, String> {
+    let args =
+        format!("run --quiet --release -p aoc -- run --year {year} --day {day} --format json");
+    let output = cargo(&args.split(' ').collect::<Vec<_>>(), Stdio::piped())?;
+    // Parts that fail make the run fail too, but they're still in the report
+    serde_json::from_slice(&output.stdout).map_err(|_| "The solution didn't run".to_string())
+}
+
+/// Runs the day's tests, then solves its inputs and compares the answers to `previous`.
+/// Gives back the answers, to compare the next run's to.
+fn run_once(year: u32, day: u32, previous: Option<&RunAnswers>) -> Option<RunAnswers> {
+    let package = format!("y{year}_day_{day}");
+    match cargo(&["test", "--quiet", "-p", &package], Stdio::inherit()) {
+        Ok(output) if output.status.success() => println!("Tests passed"),
+        Ok(_) => println!("Tests FAILED"),
+        Err(err) => eprintln!("error: {err}"),
+    }
+
+    let report = match solve(year, day) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return previous.cloned();
+        }
+    };
+    let answers = run_answers(&report);
+    // The registry is reloaded every time, so answers recorded while watching count
+    let registry = Answers::load(&Answers::default_path()).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        Answers::default()
+    });
+    for line in describe_answers(year, day, &answers, previous, &registry) {
+        println!("{line}");
+    }
+    Some(answers)
+}
+
+/// Runs the day's tests and solution every time one of its files changes, until it's interrupted.
+pub fn watch(year: u32, day: u32) -> Result<(), String> {
+    let dir = day_dir(year, day);
+    if !dir.join("Cargo.toml").exists() {
+        return Err(format!(
+            "{year}/day_{day} doesn't exist, `aoc new` creates it"
+        ));
+    }
+
+    println!("Watching {year}/day_{day}");
+    let mut snapshot = Snapshot::take(&dir);
+    let mut answers = run_once(year, day, None);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let mut next = Snapshot::take(&dir);
+        let changes = next.changes(&snapshot);
+        let Some(changed) = changes.first() else {
+            continue;
+        };
+        // Waiting for the files to settle, so saving several at once only runs the day once
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let settled = Snapshot::take(&dir);
+            if settled == next {
+                break;
+            }
+            next = settled;
+        }
+
+        let changed = changed.strip_prefix(&dir).unwrap_or(changed);
+        println!();
+        println!("--- {} changed ---", changed.display());
+        snapshot = next;
+        answers = run_once(year, day, answers.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::report::{AnswerReport, InputReport, ParseErrorReport, PartReport, Timing};
+
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("src").join("lib.rs"), "").unwrap();
+        fs::write(dir.join("input.txt"), "").unwrap();
+        let before = Snapshot::take(dir);
+        assert_eq!(before.0.len(), 2);
+
+        // Build output and editors' scratch files don't count
+        fs::write(dir.join("target").join("out"), "").unwrap();
+        fs::write(dir.join("src").join(".lib.rs.swp"), "").unwrap();
+        fs::write(dir.join("src").join("lib.rs~"), "").unwrap();
+        assert_eq!(Snapshot::take(dir).changes(&before), Vec::<PathBuf>::new());
+
+        let later = SystemTime::now() + Duration::from_secs(10);
+        File::options()
+            .write(true)
+            .open(dir.join("src").join("lib.rs"))
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        fs::remove_file(dir.join("input.txt")).unwrap();
+        fs::write(dir.join("example.txt"), "").unwrap();
+        let mut changes = Snapshot::take(dir).changes(&before);
+        changes.sort();
+        assert_eq!(
+            changes,
+            vec![
+                dir.join("example.txt"),
+                dir.join("input.txt"),
+                dir.join("src").join("lib.rs"),
+            ]
+        );
+    }
+
+    fn timing() -> Timing {
+        Timing {
+            time_ns: 1,
+            allocations: 0,
+            bytes: 0,
+        }
+    }
+
+    #[test]
+    fn test_run_answers() {
+        let report = RunReport {
+            year: 2023,
+            day: 7,
+            inputs: vec![
+                InputReport {
+                    input: "input.txt".to_string(),
+                    parse: timing(),
+                    parse_error: None,
+                    parts: vec![PartReport {
+                        part: 1,
+                        answer: Some(AnswerReport::Number(6440)),
+                        error: None,
+                        timing: timing(),
+                    }],
+                },
+                InputReport {
+                    input: "inputs/bad.txt".to_string(),
+                    parse: timing(),
+                    parse_error: Some(ParseErrorReport::new(
+                        "bad.txt".to_string(),
+                        "expected a hand".to_string(),
+                        None,
+                        String::new(),
+                    )),
+                    parts: vec![],
+                },
+            ],
+        };
+        assert_eq!(
+            run_answers(&report),
+            RunAnswers::from([
+                (("input.txt".to_string(), 1), Ok("6440".to_string())),
+                (
+                    ("inputs/bad.txt".to_string(), 1),
+                    Err("expected a hand".to_string())
+                ),
+                (
+                    ("inputs/bad.txt".to_string(), 2),
+                    Err("expected a hand".to_string())
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_describe_answers() {
+        let registry = Answers::parse(
+            "2023 7 1 input.txt correct 6440\n\
+             2023 7 2 input.txt too-high 6000\n",
+        )
+        .unwrap();
+        let answers = |part_1: Result<&str, &str>, part_2: Result<&str, &str>| {
+            RunAnswers::from([
+                (
+                    ("input.txt".to_string(), 1),
+                    part_1.map(str::to_string).map_err(str::to_string),
+                ),
+                (
+                    ("input.txt".to_string(), 2),
+                    part_2.map(str::to_string).map_err(str::to_string),
+                ),
+            ])
+        };
+
+        let first = answers(Ok("6440"), Ok("7000"));
+        assert_eq!(
+            describe_answers(2023, 7, &first, None, &registry),
+            vec![
+                "input.txt, part 1: 6440 (correct)",
+                "input.txt, part 2: 7000 (too high)",
+            ]
+        );
+
+        let second = answers(Err("no pairs"), Ok("5905"));
+        assert_eq!(
+            describe_answers(2023, 7, &second, Some(&first), &registry),
+            vec![
+                "input.txt, part 1: error: no pairs (was 6440; expected 6440)",
+                "input.txt, part 2: 5905 (was 7000)",
+            ]
+        );
+
+        let third = answers(Ok("6441"), Ok("5905"));
+        assert_eq!(
+            describe_answers(2023, 7, &third, Some(&second), &registry),
+            vec![
+                "input.txt, part 1: 6441 (was an error; wrong, expected 6440)",
+                "input.txt, part 2: 5905 (unchanged)",
+            ]
+        );
+    }
+}
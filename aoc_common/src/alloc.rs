@@ -0,0 +1,100 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    ops::Sub,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting every allocation made through it.
+///
+/// Counting only happens once it's installed by the binary:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn count(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    /// Growing a buffer counts as a new allocation of its new size, since that's what it usually costs.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Totals of the allocations made through [`CountingAllocator`].
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+}
+
+impl AllocStats {
+    /// Everything allocated since the program started.
+    pub fn current() -> Self {
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Sub for AllocStats {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            allocations: self.allocations - rhs.allocations,
+            bytes: self.bytes - rhs.bytes,
+        }
+    }
+}
+
+/// Runs `f`, counting the allocations it makes.
+/// The counters are shared by every thread, so allocations made concurrently elsewhere are counted too.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let before = AllocStats::current();
+    let result = f();
+    (result, AllocStats::current() - before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_count_allocations() {
+        let (v, stats) = count_allocations(|| Vec::<u64>::with_capacity(100));
+        assert_eq!(v.capacity(), 100);
+        // Other tests may be allocating at the same time
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes >= 800);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::SolveError;
+
+/// The integers answers are computed in, with the checked operations solutions use to stay correct on inputs
+/// bigger than the puzzle's.
+pub trait CheckedInt: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedInt for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(u32, u64, u128, usize, i32, i64, i128);
+
+/// `sum` and `product` that give `None` instead of wrapping around when the result doesn't fit.
+pub trait CheckedIterator: Iterator {
+    fn checked_sum(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CheckedInt,
+    {
+        self.try_fold(Self::Item::ZERO, CheckedInt::checked_add)
+    }
+
+    fn checked_product(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CheckedInt,
+    {
+        self.try_fold(Self::Item::ONE, CheckedInt::checked_mul)
+    }
+}
+
+impl<I: Iterator> CheckedIterator for I {}
+
+/// Turns the `None` of a checked operation into a [`SolveError::Overflow`] saying what overflowed.
+pub trait OrOverflow<T> {
+    fn or_overflow(self, what: &str) -> Result<T, SolveError>;
+}
+
+impl<T> OrOverflow<T> for Option<T> {
+    fn or_overflow(self, what: &str) -> Result<T, SolveError> {
+        self.ok_or_else(|| SolveError::Overflow(what.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!([1u32, 2, 3].into_iter().checked_sum(), Some(6));
+        assert_eq!([u32::MAX, 1].into_iter().checked_sum(), None);
+        assert_eq!([-5i32, i32::MIN].into_iter().checked_sum(), None);
+        assert_eq!(std::iter::empty::<u64>().checked_sum(), Some(0));
+    }
+
+    #[test]
+    fn test_checked_product() {
+        assert_eq!([2u64, 3, 4].into_iter().checked_product(), Some(24));
+        assert_eq!([1u64 << 32, 1 << 32].into_iter().checked_product(), None);
+        assert_eq!(std::iter::empty::<i64>().checked_product(), Some(1));
+    }
+
+    #[test]
+    fn test_or_overflow() {
+        assert_eq!(Some(1).or_overflow("the sum"), Ok(1));
+        assert_eq!(
+            None::<u32>.or_overflow("the sum").unwrap_err().to_string(),
+            "Overflowed computing the sum"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::{convert::Infallible, error::Error, fmt, ops::Range};
+
+/// Where in the input an error happened.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Location {
+    /// 1-based line number
+    pub line: usize,
+    /// Byte range of the offending part of the line, or the whole line when it's unknown
+    pub span: Option<Range<usize>>,
+}
+
+/// An error that can point at the part of the input that caused it.
+pub trait Diagnose: Error {
+    fn location(&self) -> Option<Location>;
+
+    /// The error's message, without its location
+    fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Diagnose for Infallible {
+    fn location(&self) -> Option<Location> {
+        match *self {}
+    }
+}
+
+/// Renders an error like a compiler diagnostic, quoting the offending line with a caret under the bad span:
+///
+/// ```text
+/// error: expected a number, found "4x"
+///  --> 2023/day_4/input.txt:1:12
+///   |
+/// 1 | Card 1: 41 4x | 83 86
+///   |            ^^
+/// ```
+pub struct Diagnostic<'a> {
+    file: &'a str,
+    source: &'a str,
+    error: &'a dyn Diagnose,
+}
+
+impl<'a> Diagnostic<'a> {
+    pub fn new(file: &'a str, source: &'a str, error: &'a dyn Diagnose) -> Self {
+        Self {
+            file,
+            source,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "error: {}", self.error.message())?;
+        let Some(location) = self.error.location() else {
+            return write!(f, " --> {}", self.file);
+        };
+        let Some(text) = self.source.lines().nth(location.line - 1) else {
+            return write!(f, " --> {}:{}", self.file, location.line);
+        };
+
+        // Spans are in bytes, but columns and carets are counted in characters
+        let span = location.span.unwrap_or(0..text.len());
+        let start = floor_char_boundary(text, span.start);
+        let end = floor_char_boundary(text, span.end).max(start);
+        let column = text[..start].chars().count() + 1;
+        let width = text[start..end].chars().count().max(1);
+
+        let line_number = location.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        writeln!(f, "{gutter}--> {}:{}:{column}", self.file, location.line)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_number} | {text}")?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        )
+    }
+}
+
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct BadToken(Option<Location>);
+
+    impl fmt::Display for BadToken {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "bad token")
+        }
+    }
+
+    impl Error for BadToken {}
+
+    impl Diagnose for BadToken {
+        fn location(&self) -> Option<Location> {
+            self.0.clone()
+        }
+    }
+
+    fn render(source: &str, location: Option<Location>) -> String {
+        Diagnostic::new("input.txt", source, &BadToken(location)).to_string()
+    }
+
+    #[test]
+    fn test_caret_under_span() {
+        let location = Location {
+            line: 2,
+            span: Some(11..13),
+        };
+        assert_eq!(
+            render("Card 1: 41 48 | 83\nCard 2: 13 3x | 61", Some(location)),
+            "error: bad token\n --> input.txt:2:12\n  |\n2 | Card 2: 13 3x | 61\n  |            ^^"
+        );
+    }
+
+    #[test]
+    fn test_whole_line_without_span() {
+        let location = Location {
+            line: 1,
+            span: None,
+        };
+        assert_eq!(
+            render("50 98", Some(location)),
+            "error: bad token\n --> input.txt:1:1\n  |\n1 | 50 98\n  | ^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_empty_span() {
+        let location = Location {
+            line: 1,
+            span: Some(3..3),
+        };
+        assert_eq!(
+            render("abc", Some(location)),
+            "error: bad token\n --> input.txt:1:4\n  |\n1 | abc\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn test_without_location() {
+        assert_eq!(render("", None), "error: bad token\n --> input.txt");
+        let location = Location {
+            line: 3,
+            span: None,
+        };
+        assert_eq!(
+            render("abc", Some(location)),
+            "error: bad token\n --> input.txt:3"
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+
+use crate::{input::PuzzleInput, solution::Solution};
+
+/// Parses arbitrary bytes, and when they make a valid input, checks that writing the input back and parsing it
+/// again gives the same input. Panics otherwise, which is what the fuzz targets in fuzz/ look for.
+///
+/// Returns the parsed input, for targets that go on to solve it.
+pub fn check_round_trip<S: Solution>(data: &[u8]) -> Option<S::Input>
+where
+    S::Input: PartialEq + Debug,
+{
+    let text = std::str::from_utf8(data).ok()?;
+    let input = S::parse(&PuzzleInput::new(text)).ok()?;
+    if let Some(serialized) = S::serialize(&input) {
+        let reparsed = S::parse(&PuzzleInput::new(&serialized))
+            .unwrap_or_else(|err| panic!("Serialized input doesn't parse ({err}):\n{serialized}"));
+        assert_eq!(input, reparsed, "Serialized as:\n{serialized}");
+    }
+    Some(input)
+}
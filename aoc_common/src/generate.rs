@@ -0,0 +1,32 @@
+use rand::SeedableRng;
+pub use rand::{seq::SliceRandom, Rng};
+
+use crate::{input::PuzzleInput, solution::Solution};
+
+/// Makes up puzzle inputs. Unlike `rand`'s `StdRng`, it's guaranteed to make the same values from the same seed
+/// on every platform, so generated inputs can be reproduced from their seed alone.
+pub type InputRng = rand_chacha::ChaCha8Rng;
+
+pub fn rng(seed: u64) -> InputRng {
+    InputRng::seed_from_u64(seed)
+}
+
+/// Checks that a day's generator makes the same input from the same seed, and that the input can be solved.
+///
+/// Meant for the days' tests, so it panics with the offending input when it's wrong.
+pub fn assert_generates_valid<S: Solution>(size: usize) {
+    let input = S::generate(size, &mut rng(7)).expect("The day should have a generator");
+    assert_eq!(
+        Some(&input),
+        S::generate(size, &mut rng(7)).as_ref(),
+        "The same seed should make the same input"
+    );
+
+    let parsed = S::parse(&PuzzleInput::new(&input))
+        .unwrap_or_else(|err| panic!("Generated input doesn't parse ({err}):\n{input}"));
+    for (part, result) in [(1, S::part_1(&parsed)), (2, S::part_2(&parsed))] {
+        if let Err(err) = result {
+            panic!("Part {part} can't solve the generated input ({err}):\n{input}");
+        }
+    }
+}
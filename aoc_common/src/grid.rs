@@ -0,0 +1,267 @@
+use std::{collections::VecDeque, fmt, ops};
+
+use crate::position::{Direction, Direction8, Position};
+
+/// A dense rectangle of cells, addressed by [`Position`] with `(0, 0)` at the top left.
+///
+/// Positions outside the grid are allowed anywhere one is taken, they just don't have a cell,
+/// so looking around the edges doesn't need any special casing.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Lays out `rows` top to bottom. Rows shorter than the longest one are padded with `fill`.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let rows: Vec<Vec<T>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        (0..self.width as i32).contains(&pos.x()) && (0..self.height as i32).contains(&pos.y())
+    }
+
+    fn index(&self, pos: Position) -> Option<usize> {
+        pos.to_index(self.width).filter(|&i| i < self.cells.len())
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Position::from_index(i, width))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "Column {x} is outside a grid {} wide",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The positions sharing a side with `pos` that are in the grid.
+    pub fn neighbours_4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| pos.step(direction))
+            .filter(|&p| self.contains(p))
+    }
+
+    /// The positions around `pos`, diagonals included, that are in the grid.
+    pub fn neighbours_8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| pos.step(direction))
+            .filter(|&p| self.contains(p))
+    }
+
+    /// The region of cells matching `belongs` that can be reached from `start` through their sides,
+    /// closest first. It's empty when `start` itself doesn't match.
+    pub fn region(&self, start: Position, belongs: impl Fn(&T) -> bool) -> Vec<Position> {
+        let mut seen = Grid::new(self.width, self.height, false);
+        let mut region = vec![];
+        let mut queue = VecDeque::new();
+        if self.get(start).is_some_and(&belongs) {
+            seen[start] = true;
+            queue.push_back(start);
+        }
+        while let Some(pos) = queue.pop_front() {
+            region.push(pos);
+            for next in self.neighbours_4(pos) {
+                if !seen[next] && belongs(&self[next]) {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        region
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Reads a grid drawn one row per line. Rows shorter than the longest one are padded with spaces.
+    pub fn parse(text: &str) -> Self {
+        Self::from_rows(text.lines().map(str::chars), ' ')
+    }
+}
+
+impl<T> ops::Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> ops::IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Draws the grid one row per line, with each cell written right after the other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#..\n.#\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position(0, 0)], '#');
+        assert_eq!(grid[Position(2, 1)], ' ');
+        assert_eq!(grid.get(Position(3, 0)), None);
+        assert_eq!(grid.get(Position(0, -1)), None);
+        assert_eq!(grid.to_string(), "#..\n.# \n");
+        assert_eq!(Grid::parse("").to_string(), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "outside a 3x2 grid")]
+    fn test_index_outside() {
+        let _ = Grid::parse("#..\n.#.")[Position(1, 2)];
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse("abc\ndef");
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.iter().nth(4), Some((Position(1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours_4(Position(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8(Position(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours_4(Position(0, 0)).collect::<Vec<_>>(),
+            vec![Position(1, 0), Position(0, 1)]
+        );
+        assert_eq!(grid.neighbours_8(Position(0, 0)).count(), 3);
+        // Just outside the grid still has neighbours in it
+        assert_eq!(
+            grid.neighbours_8(Position(-1, 0)).collect::<Vec<_>>(),
+            vec![Position(0, 0), Position(0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_region() {
+        let grid = Grid::parse("aab\nbab\nbbb\n.a.");
+        let mut a = grid.region(Position(0, 0), |&c| c == 'a');
+        a.sort_by_key(|p| (p.y(), p.x()));
+        assert_eq!(a, vec![Position(0, 0), Position(1, 0), Position(1, 1)]);
+        // Diagonals don't connect
+        assert_eq!(grid.region(Position(2, 0), |&c| c == 'b').len(), 6);
+        assert!(grid.region(Position(0, 0), |&c| c == 'b').is_empty());
+        assert!(grid.region(Position(5, 5), |_| true).is_empty());
+    }
+
+    #[test]
+    fn test_map() {
+        let mut grid = Grid::parse("#.\n.#").map(|&c| c == '#');
+        grid[Position(1, 0)] = true;
+        assert_eq!(
+            grid.map(|&wall| if wall { '#' } else { '.' }).to_string(),
+            "##\n.#\n"
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use std::{fs, ops::Deref, path::Path};
+
+use crate::grid::Grid;
+
+/// A puzzle input with the quirks of how it was saved ironed out, so parsers don't have to care:
+/// lines end with `\n` rather than `\r\n`, there's no byte order mark, no line has trailing whitespace
+/// and there are no blank lines at the end.
+///
+/// Blank lines at the start are kept, so line numbers still match the file's.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct PuzzleInput {
+    text: String,
+}
+
+/// A block of lines separated from the rest of the input by blank lines.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Section<'a> {
+    /// 1-based line number of the section's first line in the whole input
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// The section's lines, along with their 1-based line numbers in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let first = self.line;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (first + i, line))
+    }
+}
+
+impl PuzzleInput {
+    pub fn new(raw: &str) -> Self {
+        // An editor re-saving the file with its own byte order mark can leave more than one
+        let raw = raw.trim_start_matches('\u{feff}');
+        let mut text = String::with_capacity(raw.len());
+        for line in raw.lines() {
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+        text.truncate(text.trim_end().len());
+        Self { text }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    /// The blocks of lines the input is split into by one or more blank lines.
+    pub fn sections(&self) -> Vec<Section<'_>> {
+        let mut sections = vec![];
+        // Line number and offset of the section being read
+        let mut start = None;
+        let mut offset = 0;
+        for (i, line) in self.text.split('\n').enumerate() {
+            if line.is_empty() {
+                if let Some((line, from)) = start.take() {
+                    sections.push(Section {
+                        line,
+                        text: &self.text[from..offset - 1],
+                    });
+                }
+            } else if start.is_none() {
+                start = Some((i + 1, offset));
+            }
+            offset += line.len() + 1;
+        }
+        if let Some((line, from)) = start {
+            sections.push(Section {
+                line,
+                text: &self.text[from..],
+            });
+        }
+        sections
+    }
+
+    /// The input as a grid of characters, for puzzles laid out on one.
+    /// Lines shorter than the longest one are padded with spaces, like their trimmed whitespace was put back.
+    pub fn grid(&self) -> Grid<char> {
+        Grid::parse(&self.text)
+    }
+}
+
+impl Deref for PuzzleInput {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Reads a puzzle input file, panicking with the offending path if it can't be read.
+pub fn read_input(path: impl AsRef<Path>) -> PuzzleInput {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Couldn't read input {}: {}", path.display(), err));
+    PuzzleInput::new(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "does-not-exist.txt")]
+    fn test_read_missing_input() {
+        read_input("does-not-exist.txt");
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            PuzzleInput::new("\u{feff}ab  \r\ncd\t\r\n\r\n  ef\r\n \n\n").as_str(),
+            "ab\ncd\n\n  ef"
+        );
+        assert_eq!(PuzzleInput::new("\n\nab\n").as_str(), "\n\nab");
+        assert_eq!(PuzzleInput::new(" \r\n").as_str(), "");
+        assert_eq!(PuzzleInput::new("\u{feff}\u{feff}#").as_str(), "#");
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = PuzzleInput::new("\na\nb\n\n \n\nc\r\n\r\nd\ne\n\n");
+        let sections = input.sections();
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    line: 2,
+                    text: "a\nb"
+                },
+                Section { line: 7, text: "c" },
+                Section {
+                    line: 9,
+                    text: "d\ne"
+                },
+            ]
+        );
+        assert_eq!(
+            sections[2].lines().collect::<Vec<_>>(),
+            vec![(9, "d"), (10, "e")]
+        );
+        assert!(PuzzleInput::new("\n\n").sections().is_empty());
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(
+            PuzzleInput::new("#.\r\n.#\r\n").grid(),
+            Grid::from_rows([['#', '.'], ['.', '#']], ' ')
+        );
+        assert_eq!(PuzzleInput::new("#. \n#").grid().to_string(), "#.\n# \n");
+    }
+}
@@ -0,0 +1,18 @@
+pub mod alloc;
+pub mod checked;
+pub mod diagnostic;
+pub mod fuzz;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parse;
+pub mod position;
+pub mod solution;
+pub mod visualize;
+
+pub use diagnostic::Diagnostic;
+pub use grid::Grid;
+pub use input::{read_input, PuzzleInput};
+pub use position::{Direction, Direction8, Position, Position3};
+pub use solution::{Answer, Solution, SolveError, SolveResult};
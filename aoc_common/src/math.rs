@@ -0,0 +1,289 @@
+use std::fmt;
+
+/// Greatest common divisor using the euclidean algorithm.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it doesn't fit in 64 bits. It's 0 when either number is.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all the numbers, 1 when there are none, or `None` if it doesn't fit in 64 bits.
+pub fn lcm_all(values: &[u64]) -> Option<u64> {
+    values.iter().try_fold(1, |acc, &v| lcm(acc, v))
+}
+
+/// [`lcm`] for 128 bit numbers.
+pub fn lcm_u128(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd_u128(a, b)).checked_mul(b)
+}
+
+/// [`lcm_all`] for 128 bit numbers.
+pub fn lcm_all_u128(values: &[u128]) -> Option<u128> {
+    values.iter().try_fold(1, |acc, &v| lcm_u128(acc, v))
+}
+
+/// Finds the gcd `g` of `a` and `b` along with `x` and `y` such that `a * x + b * y = g`, returned as `(g, x, y)`.
+/// The gcd is never negative. Everything is worked out in 128 bits, since the gcd of `i64::MIN` and 0 doesn't fit
+/// in an `i64`.
+pub fn extended_gcd(a: i64, b: i64) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (i128::from(a), i128::from(b));
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a * b % m` without overflowing, however big the numbers are.
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (mut a, mut b) = (a % m, b % m);
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// `(a + b) % m` for `a` and `b` already below `m`, without overflowing.
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `(a - b) % m` for `a` and `b` already below `m`, without going negative.
+fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        a + (m - b)
+    }
+}
+
+/// The `x` in `0..m` such that `a * x % m == 1`, if there is one, which is when `a` and `m` are coprime.
+pub fn mod_inverse(a: u128, m: u128) -> Option<u128> {
+    assert!(m > 0, "The modulus can't be 0");
+    // The extended euclidean algorithm, keeping the coefficients modulo m so they never go negative
+    let (mut old_r, mut r) = (a % m, m);
+    let (mut old_x, mut x) = (1 % m, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, sub_mod(old_x, mul_mod(q, x, m), m));
+    }
+    (old_r == 1).then_some(old_x)
+}
+
+/// Why a system of congruences has no solution [`crt`] can give.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CrtError {
+    /// Two of the congruences contradict each other
+    NoSolution,
+    /// The solutions repeat with a period too big for the integer type
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSolution => write!(f, "The congruences have no common solution"),
+            Self::Overflow => write!(f, "The solutions repeat with too large a period"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves the system `x ≡ residue (mod modulus)` for each `(residue, modulus)` in `congruences`, with the chinese
+/// remainder theorem. The moduli don't have to be coprime.
+///
+/// The solutions are every `x` that's `solution` modulo `period`, returned as `(solution, period)` with `solution`
+/// in `0..period`. Without any congruences, every number is a solution.
+pub fn crt_u128(congruences: &[(u128, u128)]) -> Result<(u128, u128), CrtError> {
+    let mut solution = 0;
+    let mut period = 1;
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "The modulus can't be 0");
+        let residue = residue % modulus;
+
+        // Looking for a k such that solution + period * k ≡ residue (mod modulus)
+        let g = gcd_u128(period, modulus);
+        let diff = sub_mod(residue, solution % modulus, modulus);
+        if !diff.is_multiple_of(g) {
+            return Err(CrtError::NoSolution);
+        }
+        let reduced = modulus / g;
+        let inverse = mod_inverse(period / g % reduced, reduced)
+            .expect("period / g and modulus / g should be coprime");
+        let k = mul_mod(diff / g, inverse, reduced);
+
+        let new_period = (period / g)
+            .checked_mul(modulus)
+            .ok_or(CrtError::Overflow)?;
+        // k < modulus / g, so neither of these can go past the new period
+        solution += period * k;
+        period = new_period;
+    }
+    Ok((solution, period))
+}
+
+/// [`crt_u128`] for 64 bit numbers.
+pub fn crt(congruences: &[(u64, u64)]) -> Result<(u64, u64), CrtError> {
+    let congruences: Vec<(u128, u128)> = congruences
+        .iter()
+        .map(|&(residue, modulus)| (residue.into(), modulus.into()))
+        .collect();
+    let (solution, period) = crt_u128(&congruences)?;
+    let period = u64::try_from(period).map_err(|_| CrtError::Overflow)?;
+    Ok((solution as u64, period))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(7, 13), 1);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm_all(&[]), Some(1));
+        assert_eq!(lcm_all(&[2, 3, 4, 6]), Some(12));
+        // Not just the product divided by the overall gcd, which would be 48
+        assert_eq!(lcm_all(&[4, 6, 8]), Some(24));
+        assert_eq!(lcm_all(&[6, 10, 15]), Some(30));
+        assert_eq!(lcm_u128(u64::MAX.into(), 2), Some(u128::from(u64::MAX) * 2));
+        assert_eq!(lcm_all_u128(&[1 << 100, 3, 1 << 20]), Some(3 << 100));
+    }
+
+    #[test]
+    fn test_lcm_extremes() {
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm(1 << 63, 1 << 62), Some(1 << 63));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm_all(&[u64::MAX, u64::MAX - 1]), None);
+        assert_eq!(lcm_u128(u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(lcm_u128(u128::MAX, 2), None);
+        assert_eq!(lcm_all_u128(&[u128::MAX, 1, u128::MAX - 1]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(0, 5), (5, 0, 1));
+        let (g, x, y) = extended_gcd(-12, 18);
+        assert_eq!(g, 6);
+        assert_eq!(-12 * x + 18 * y, 6);
+    }
+
+    #[test]
+    fn test_extended_gcd_extremes() {
+        assert_eq!(extended_gcd(i64::MIN, 0), (1 << 63, -1, 0));
+        assert_eq!(extended_gcd(0, i64::MIN), (1 << 63, 0, -1));
+        for (a, b) in [
+            (i64::MIN, i64::MIN),
+            (i64::MIN, i64::MAX),
+            (i64::MAX, i64::MIN),
+            (i64::MIN, -1),
+            (i64::MAX, i64::MAX - 1),
+            (i64::MIN, 1 << 62),
+        ] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(
+                g,
+                gcd_u128(i128::from(a).unsigned_abs(), i128::from(b).unsigned_abs()) as i128
+            );
+            assert_eq!(i128::from(a) * x + i128::from(b) * y, g, "{a}, {b}");
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+        let big = u128::MAX - 158; // a prime
+        assert_eq!(mul_mod(mod_inverse(2, big).unwrap(), 2, big), 1);
+        assert_eq!(mul_mod(mod_inverse(big - 1, big).unwrap(), big - 1, big), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        // Moduli that share a factor
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Ok((11, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), Err(CrtError::NoSolution));
+        assert_eq!(crt(&[(7, 5)]), Ok((2, 5)));
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        assert_eq!(
+            crt(&[(0, u64::MAX), (1, u64::MAX - 1)]),
+            Err(CrtError::Overflow)
+        );
+        let (solution, period) =
+            crt_u128(&[(0, u64::MAX.into()), (1, (u64::MAX - 1).into())]).unwrap();
+        assert_eq!(period, u128::from(u64::MAX) * u128::from(u64::MAX - 1));
+        assert_eq!(solution % u128::from(u64::MAX), 0);
+        assert_eq!(solution % u128::from(u64::MAX - 1), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn test_crt_against_search(
+            congruences in prop::collection::vec((0u64..50, 1u64..20), 1..4)
+        ) {
+            let period = lcm_all(&congruences.iter().map(|&(_, m)| m).collect::<Vec<_>>()).unwrap();
+            let solves = |x: u64| congruences.iter().all(|&(r, m)| x % m == r % m);
+            match (0..period).find(|&x| solves(x)) {
+                Some(x) => prop_assert_eq!(crt(&congruences), Ok((x, period))),
+                None => prop_assert_eq!(crt(&congruences), Err(CrtError::NoSolution)),
+            }
+        }
+    }
+}
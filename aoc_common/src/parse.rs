@@ -0,0 +1,387 @@
+use std::{error::Error, fmt, ops::Range, str::FromStr};
+
+use crate::diagnostic::{Diagnose, Location};
+
+/// Describes what a parser found in the input, and what it expected to find there instead.
+pub trait ErrorKind {
+    /// The offending part of the input
+    fn token(&self) -> &str;
+    /// A description of the form the parser expected, e.g. `a number`
+    fn expected(&self) -> &'static str;
+}
+
+/// A parsing failure, positioned on the line of the input it happened on.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseError<K> {
+    /// 1-based line number, 0 until the error is attached to a line with [`ParseError::in_line`]
+    pub line: usize,
+    /// Byte range of the offending token within its line
+    pub span: Option<Range<usize>>,
+    pub kind: K,
+}
+
+impl<K> ParseError<K> {
+    pub fn new(kind: K) -> Self {
+        Self {
+            line: 0,
+            span: None,
+            kind,
+        }
+    }
+
+    /// Points the error at a known range of its line, for when the token could appear more than once.
+    pub fn with_span(self, span: Range<usize>) -> Self {
+        Self {
+            span: Some(span),
+            ..self
+        }
+    }
+}
+
+impl<K: ErrorKind> ParseError<K> {
+    /// Attaches the error to `text`, the `line`th line of the input.
+    /// Unless the span is already known, it's found by looking for the offending token in the line.
+    pub fn in_line(self, line: usize, text: &str) -> Self {
+        let span = self.span.clone().or_else(|| {
+            let token = self.kind.token();
+            text.find(token).map(|start| start..start + token.len())
+        });
+        Self { line, span, ..self }
+    }
+}
+
+impl<K> From<K> for ParseError<K> {
+    fn from(kind: K) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl<K: ErrorKind> ParseError<K> {
+    fn message(&self) -> String {
+        format!(
+            "expected {}, found {:?}",
+            self.kind.expected(),
+            self.kind.token()
+        )
+    }
+}
+
+impl<K: ErrorKind> fmt::Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}", self.line)?;
+        if let Some(span) = &self.span {
+            write!(f, ", column {}", span.start + 1)?;
+        }
+        write!(f, ": {}", self.message())
+    }
+}
+
+impl<K: ErrorKind + fmt::Debug> Error for ParseError<K> {}
+
+impl<K: ErrorKind + fmt::Debug> Diagnose for ParseError<K> {
+    fn location(&self) -> Option<Location> {
+        (self.line > 0).then(|| Location {
+            line: self.line,
+            span: self.span.clone(),
+        })
+    }
+
+    fn message(&self) -> String {
+        ParseError::message(self)
+    }
+}
+
+/// Parses every line of `s`, attaching the line it happened on to the first error.
+pub fn parse_lines<T, K: ErrorKind>(
+    s: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError<K>>,
+) -> Result<Vec<T>, ParseError<K>> {
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|err| err.in_line(i + 1, line)))
+        .collect()
+}
+
+/// A piece of a line, which remembers where in the line it is so errors about it can point at it.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Field<'a> {
+    pub text: &'a str,
+    /// Byte offset of the field in its line
+    pub start: usize,
+}
+
+impl<'a> Field<'a> {
+    /// A whole line.
+    pub fn new(line: &'a str) -> Self {
+        Self {
+            text: line,
+            start: 0,
+        }
+    }
+
+    /// The part of the field from byte `from` to byte `to`.
+    fn slice(&self, from: usize, to: usize) -> Self {
+        Self {
+            text: &self.text[from..to],
+            start: self.start + from,
+        }
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+
+    /// An error about the field, which `kind` is made from the field's text.
+    pub fn error<K>(&self, kind: impl FnOnce(String) -> K) -> ParseError<K> {
+        ParseError::new(kind(self.text.to_string())).with_span(self.span())
+    }
+
+    /// Parses the whole field, failing with the error `kind` makes when it can't be.
+    pub fn parse<T: FromStr, K>(&self, kind: impl FnOnce(String) -> K) -> Result<T, ParseError<K>> {
+        self.text.parse().map_err(|_| self.error(kind))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Self> {
+        self.text
+            .starts_with(prefix)
+            .then(|| self.slice(prefix.len(), self.text.len()))
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Self> {
+        self.text
+            .ends_with(suffix)
+            .then(|| self.slice(0, self.text.len() - suffix.len()))
+    }
+
+    /// The pieces of the field between each `separator`.
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Field<'a>> {
+        let mut from = 0;
+        self.text.split(separator).map(move |piece| {
+            let field = self.slice(from, from + piece.len());
+            from += piece.len() + separator.len();
+            field
+        })
+    }
+
+    /// The field's words, with any amount of whitespace between them.
+    pub fn words(self) -> impl Iterator<Item = Field<'a>> {
+        self.text.split_whitespace().map(move |word| {
+            // Words are subslices of the text, so their offset can be worked out from their address
+            let from = word.as_ptr() as usize - self.text.as_ptr() as usize;
+            self.slice(from, from + word.len())
+        })
+    }
+
+    /// Each of the field's characters, as a field of its own.
+    pub fn chars(self) -> impl Iterator<Item = Field<'a>> {
+        self.text
+            .char_indices()
+            .map(move |(i, c)| self.slice(i, i + c.len_utf8()))
+    }
+
+    /// Parses each of the pieces between `separator` with `parse`, stopping at the first error.
+    pub fn list<T, K>(
+        self,
+        separator: &'a str,
+        parse: impl FnMut(Field<'a>) -> Result<T, ParseError<K>>,
+    ) -> Result<Vec<T>, ParseError<K>> {
+        self.split(separator).map(parse).collect()
+    }
+
+    /// Parses a list of numbers separated by whitespace, like `41 48 83 86`.
+    pub fn numbers<T: FromStr, K>(
+        self,
+        kind: impl Fn(String) -> K,
+    ) -> Result<Vec<T>, ParseError<K>> {
+        self.words().map(|word| word.parse(&kind)).collect()
+    }
+}
+
+/// The layout of a line, like `"Card {}: {} | {}"`: text that has to be there as it is, with a `{}` for each field
+/// to read.
+///
+/// A field ends where the first occurrence of the text after it starts, so `"{} | {}"` splits at the first `" | "`.
+/// A field at the end of the pattern takes the rest of the line.
+pub struct Pattern<'p> {
+    /// The text around the fields, one more piece than there are fields
+    pieces: Vec<&'p str>,
+}
+
+impl<'p> Pattern<'p> {
+    pub fn new(pattern: &'p str) -> Self {
+        let pieces: Vec<&str> = pattern.split("{}").collect();
+        assert!(
+            pieces[1..pieces.len().max(2) - 1]
+                .iter()
+                .all(|piece| !piece.is_empty()),
+            "Fields in {pattern:?} need some text between them to tell where they end"
+        );
+        Self { pieces }
+    }
+
+    /// Reads the `N` fields of the pattern in `field`, failing with the error `kind` makes of the whole of it
+    /// when it doesn't fit the pattern.
+    pub fn fields<'a, const N: usize, K>(
+        &self,
+        field: Field<'a>,
+        kind: impl FnOnce(String) -> K,
+    ) -> Result<[Field<'a>; N], ParseError<K>> {
+        assert_eq!(
+            self.pieces.len() - 1,
+            N,
+            "The pattern has {} fields",
+            self.pieces.len() - 1
+        );
+        self.match_fields(field).ok_or_else(|| field.error(kind))
+    }
+
+    fn match_fields<'a, const N: usize>(&self, field: Field<'a>) -> Option<[Field<'a>; N]> {
+        let Some((last, separators)) = self.pieces[1..].split_last() else {
+            // Nothing but text
+            return (field.text == self.pieces[0]).then_some([field; N]);
+        };
+        let mut rest = field.strip_prefix(self.pieces[0])?.strip_suffix(last)?;
+        let mut fields = [rest; N];
+        for (i, separator) in separators.iter().enumerate() {
+            let end = rest.text.find(separator)?;
+            fields[i] = rest.slice(0, end);
+            rest = rest.slice(end + separator.len(), rest.text.len());
+        }
+        fields[N - 1] = rest;
+        Some(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct NotANumber(String);
+
+    impl ErrorKind for NotANumber {
+        fn token(&self) -> &str {
+            &self.0
+        }
+
+        fn expected(&self) -> &'static str {
+            "a number"
+        }
+    }
+
+    fn parse_number(s: &str) -> Result<u32, ParseError<NotANumber>> {
+        Ok(s.parse().map_err(|_| NotANumber(s.to_string()))?)
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n3", parse_number), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_lines("1\n2\nthree", parse_number),
+            Err(ParseError {
+                line: 3,
+                span: Some(0..5),
+                kind: NotANumber("three".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_in_line() {
+        let err = ParseError::new(NotANumber("x".to_string())).in_line(2, "1 2 x");
+        assert_eq!(err.span, Some(4..5));
+        // A token that can't be found is left without a span
+        let err = ParseError::new(NotANumber("y".to_string())).in_line(2, "1 2 x");
+        assert_eq!(err.span, None);
+        // An explicit span takes priority over searching
+        let err = ParseError::new(NotANumber("1".to_string()))
+            .with_span(2..3)
+            .in_line(1, "1 1");
+        assert_eq!(err.span, Some(2..3));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(NotANumber("x".to_string())).in_line(4, "1 x");
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        let line = Field::new("a  bc, d");
+        assert_eq!(
+            line.words().map(|word| word.span()).collect::<Vec<_>>(),
+            vec![0..1, 3..6, 7..8]
+        );
+        assert_eq!(
+            line.split(", ")
+                .map(|piece| piece.span())
+                .collect::<Vec<_>>(),
+            vec![0..5, 7..8]
+        );
+        assert_eq!(line.strip_prefix("a ").map(|f| f.span()), Some(2..8));
+        assert_eq!(line.strip_suffix(", d").map(|f| f.span()), Some(0..5));
+        assert_eq!(line.strip_prefix("b"), None);
+        assert_eq!(
+            Field::new("aé")
+                .chars()
+                .map(|c| c.span())
+                .collect::<Vec<_>>(),
+            vec![0..1, 1..3]
+        );
+
+        let numbers = Field::new("1  22 x3").strip_prefix("1").unwrap();
+        assert_eq!(
+            numbers.numbers::<u32, _>(NotANumber),
+            Err(ParseError::new(NotANumber("x3".to_string())).with_span(6..8))
+        );
+        assert_eq!(
+            Field::new("1, 2").list(", ", |n| n.parse(NotANumber)),
+            Ok(vec![1u8, 2])
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let card = Pattern::new("Card {}: {} | {}");
+        let [id, winning, yours] = card
+            .fields(Field::new("Card 1: 41 48 | 83 86 6"), NotANumber)
+            .unwrap();
+        assert_eq!((id.text, id.start), ("1", 5));
+        assert_eq!((winning.text, winning.start), ("41 48", 8));
+        assert_eq!((yours.text, yours.start), ("83 86 6", 16));
+        assert_eq!(
+            card.fields::<3, _>(Field::new("Card 1: 41 48"), NotANumber),
+            Err(ParseError::new(NotANumber("Card 1: 41 48".to_string())).with_span(0..13))
+        );
+        assert!(card
+            .fields::<3, _>(Field::new("Cart 1: 4 | 8"), NotANumber)
+            .is_err());
+
+        // Fields end at the first match of the text after them
+        let [key, value] = Pattern::new("{}: {}")
+            .fields(Field::new("a: b: c"), NotANumber)
+            .unwrap();
+        assert_eq!((key.text, value.text), ("a", "b: c"));
+
+        let node = Pattern::new("{} = ({}, {})");
+        assert!(node
+            .fields::<3, _>(Field::new("AAA = (BBB, CCC) "), NotANumber)
+            .is_err());
+        assert!(Pattern::new("end")
+            .fields::<0, _>(Field::new("end"), NotANumber)
+            .is_ok());
+        assert!(Pattern::new("end")
+            .fields::<0, _>(Field::new("endless"), NotANumber)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "need some text between them")]
+    fn test_adjacent_fields() {
+        Pattern::new("{}{}");
+    }
+}
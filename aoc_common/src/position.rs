@@ -0,0 +1,317 @@
+use std::ops;
+
+// Positions aren't bound to grid to allow for easy negative index lookup
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub struct Position(pub i32, pub i32);
+
+impl Position {
+    pub fn x(&self) -> i32 {
+        self.0
+    }
+
+    pub fn y(&self) -> i32 {
+        self.1
+    }
+
+    /// The number of steps between the positions when moving along the axes.
+    /// Each axis can be as far as a `u32` goes, so their sum takes a `u64`.
+    pub fn manhattan_distance(self, other: Position) -> u64 {
+        u64::from(self.0.abs_diff(other.0)) + u64::from(self.1.abs_diff(other.1))
+    }
+
+    /// The number of steps between the positions when diagonal moves are allowed too.
+    pub fn chebyshev_distance(self, other: Position) -> u32 {
+        self.0.abs_diff(other.0).max(self.1.abs_diff(other.1))
+    }
+
+    /// The position of the `index`th cell of a grid `width` wide, laid out row by row.
+    pub fn from_index(index: usize, width: usize) -> Self {
+        Position((index % width) as i32, (index / width) as i32)
+    }
+
+    /// Where the position's cell is in a grid `width` wide, laid out row by row.
+    /// Positions left of or above the grid, or past its right edge, have none.
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        let x = usize::try_from(self.0).ok().filter(|&x| x < width)?;
+        let y = usize::try_from(self.1).ok()?;
+        Some(y * width + x)
+    }
+
+    /// The position a step away in `direction`.
+    pub fn step(self, direction: impl Into<Direction8>) -> Self {
+        self + direction.into().offset()
+    }
+}
+
+/// A position in space, for the puzzles that aren't flat.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub struct Position3(pub i32, pub i32, pub i32);
+
+impl Position3 {
+    pub fn x(&self) -> i32 {
+        self.0
+    }
+
+    pub fn y(&self) -> i32 {
+        self.1
+    }
+
+    pub fn z(&self) -> i32 {
+        self.2
+    }
+
+    /// The number of steps between the positions when moving along the axes.
+    pub fn manhattan_distance(self, other: Position3) -> u64 {
+        u64::from(self.0.abs_diff(other.0))
+            + u64::from(self.1.abs_diff(other.1))
+            + u64::from(self.2.abs_diff(other.2))
+    }
+
+    /// The number of steps between the positions when diagonal moves are allowed too.
+    pub fn chebyshev_distance(self, other: Position3) -> u32 {
+        self.0
+            .abs_diff(other.0)
+            .max(self.1.abs_diff(other.1))
+            .max(self.2.abs_diff(other.2))
+    }
+}
+
+/// Implements the arithmetic operators component by component.
+macro_rules! impl_vector_ops {
+    ($t:ident, $($i:tt),+) => {
+        impl ops::Add<$t> for $t {
+            type Output = $t;
+            fn add(self, rhs: $t) -> Self::Output {
+                $t($(self.$i + rhs.$i),+)
+            }
+        }
+
+        impl ops::Sub<$t> for $t {
+            type Output = $t;
+            fn sub(self, rhs: $t) -> Self::Output {
+                $t($(self.$i - rhs.$i),+)
+            }
+        }
+
+        impl ops::Mul<i32> for $t {
+            type Output = $t;
+            fn mul(self, rhs: i32) -> Self::Output {
+                $t($(self.$i * rhs),+)
+            }
+        }
+
+        impl ops::Neg for $t {
+            type Output = $t;
+            fn neg(self) -> Self::Output {
+                $t($(-self.$i),+)
+            }
+        }
+
+        impl ops::AddAssign<$t> for $t {
+            fn add_assign(&mut self, rhs: $t) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl ops::SubAssign<$t> for $t {
+            fn sub_assign(&mut self, rhs: $t) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Position, 0, 1);
+impl_vector_ops!(Position3, 0, 1, 2);
+
+/// One of the 4 directions along the grid's axes. Up is towards row 0, like in the puzzle's drawings.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The step a move in this direction takes.
+    pub fn offset(self) -> Position {
+        Direction8::from(self).offset()
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the 8 directions to the cells around a cell, diagonals included.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise, starting from up
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The step a move in this direction takes.
+    pub fn offset(self) -> Position {
+        match self {
+            Direction8::Up => Position(0, -1),
+            Direction8::UpRight => Position(1, -1),
+            Direction8::Right => Position(1, 0),
+            Direction8::DownRight => Position(1, 1),
+            Direction8::Down => Position(0, 1),
+            Direction8::DownLeft => Position(-1, 1),
+            Direction8::Left => Position(-1, 0),
+            Direction8::UpLeft => Position(-1, -1),
+        }
+    }
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let mut pos = Position(2, -3);
+        assert_eq!(pos + Position(1, 1), Position(3, -2));
+        assert_eq!(pos - Position(1, 1), Position(1, -4));
+        assert_eq!(pos * 3, Position(6, -9));
+        assert_eq!(-pos, Position(-2, 3));
+        pos += Position(1, 0);
+        pos -= Position(0, 1);
+        assert_eq!(pos, Position(3, -4));
+        assert_eq!(
+            Position3(1, 2, 3) - Position3(3, 2, 1) * 2,
+            Position3(-5, -2, 1)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        assert_eq!(Position(1, -2).manhattan_distance(Position(-3, 4)), 10);
+        assert_eq!(Position(1, -2).chebyshev_distance(Position(-3, 4)), 6);
+        assert_eq!(
+            Position3(0, 0, 0).manhattan_distance(Position3(1, -2, 3)),
+            6
+        );
+        assert_eq!(
+            Position3(0, 0, 0).chebyshev_distance(Position3(1, -2, 3)),
+            3
+        );
+        assert_eq!(
+            Position(i32::MIN, 0).manhattan_distance(Position(i32::MAX, 0)),
+            u32::MAX.into()
+        );
+        assert_eq!(
+            Position(i32::MIN, i32::MIN).manhattan_distance(Position(i32::MAX, i32::MAX)),
+            2 * u64::from(u32::MAX)
+        );
+        assert_eq!(
+            Position3(i32::MIN, i32::MIN, i32::MIN).manhattan_distance(Position3(
+                i32::MAX,
+                i32::MAX,
+                i32::MAX
+            )),
+            3 * u64::from(u32::MAX)
+        );
+        assert_eq!(
+            Position(i32::MIN, i32::MIN).chebyshev_distance(Position(i32::MAX, i32::MAX)),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn test_indices() {
+        assert_eq!(Position::from_index(7, 3), Position(1, 2));
+        assert_eq!(Position(1, 2).to_index(3), Some(7));
+        assert_eq!(Position(3, 0).to_index(3), None);
+        assert_eq!(Position(-1, 1).to_index(3), None);
+        assert_eq!(Position(0, -1).to_index(3), None);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+        for direction in Direction::ALL {
+            assert_eq!(direction.offset(), -direction.opposite().offset());
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert!(!Direction8::from(direction).is_diagonal());
+        }
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+        assert_eq!(
+            Direction8::ALL
+                .map(|d| d.offset())
+                .iter()
+                .copied()
+                .fold(Position(0, 0), |a, b| a + b),
+            Position(0, 0)
+        );
+        assert_eq!(Position(0, 0).step(Direction::Left), Position(-1, 0));
+        assert_eq!(Position(0, 0).step(Direction8::DownRight), Position(1, 1));
+    }
+}
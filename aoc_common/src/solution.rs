@@ -0,0 +1,110 @@
+use std::fmt;
+
+use crate::{
+    diagnostic::Diagnose, generate::InputRng, input::PuzzleInput, visualize::Visualization,
+};
+
+/// A puzzle's answer. Most are numbers, but some puzzles expect text.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Raised when an input parses fine but can't be solved.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum SolveError {
+    /// The input doesn't have the structure the solution relies on
+    Unsupported(String),
+    /// A number along the way got too big for the integers it's computed in, naming which
+    Overflow(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Unsupported(reason) => write!(f, "Unsupported input: {reason}"),
+            SolveError::Overflow(what) => write!(f, "Overflowed computing {what}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+pub type SolveResult = Result<Answer, SolveError>;
+
+/// A single day's puzzle. The input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    type Error: Diagnose + 'static;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, Self::Error>;
+    fn part_1(input: &Self::Input) -> SolveResult;
+    fn part_2(input: &Self::Input) -> SolveResult;
+
+    /// Writes a parsed input back in the puzzle's format, so that parsing it again gives the same input.
+    /// Days that can't have nothing to write.
+    fn serialize(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Makes up a valid input from `rng`, `size` being how big it is in whatever unit suits the puzzle
+    /// (lines, grid width, ...). Days without a generator have nothing to make up.
+    fn generate(_size: usize, _rng: &mut InputRng) -> Option<String> {
+        None
+    }
+
+    /// Draws a parsed input, showing what the parts make of it. Some days draw it in more than one format,
+    /// days that can't draw it have nothing to show.
+    fn visualize(_input: &Self::Input) -> Vec<Visualization> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42u32).to_string(), "42");
+        assert_eq!(Answer::from(-7i32).to_string(), "-7");
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+        assert_eq!(Answer::from("FBGKOCZ".to_string()).to_string(), "FBGKOCZ");
+    }
+}
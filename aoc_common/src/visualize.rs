@@ -0,0 +1,155 @@
+use std::fmt::{self, Write};
+
+/// A drawing of a parsed input, to look at what a solution is working with when an answer comes out wrong.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Visualization {
+    /// What kind of drawing it is, which is also the extension of the file it's written to: `txt`, `svg` or `dot`
+    pub format: &'static str,
+    pub contents: String,
+}
+
+impl Visualization {
+    /// Plain text, to read in a terminal.
+    pub fn text(contents: String) -> Self {
+        Self {
+            format: "txt",
+            contents,
+        }
+    }
+
+    pub fn svg(svg: &Svg) -> Self {
+        Self {
+            format: "svg",
+            contents: svg.to_string(),
+        }
+    }
+
+    /// A Graphviz graph, which `dot -Tsvg` lays out.
+    pub fn dot(contents: String) -> Self {
+        Self {
+            format: "dot",
+            contents,
+        }
+    }
+}
+
+/// Escapes text for SVG, which is XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Quotes an id or label for a Graphviz graph.
+pub fn dot_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// An SVG image, drawn shape by shape. Shapes drawn later go over the earlier ones.
+pub struct Svg {
+    width: f64,
+    height: f64,
+    shapes: Vec<String>,
+}
+
+impl Svg {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            shapes: vec![],
+        }
+    }
+
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, fill: &str) {
+        self.shapes.push(format!(
+            r#"<rect x="{x}" y="{y}" width="{width}" height="{height}" fill="{}"/>"#,
+            escape(fill)
+        ));
+    }
+
+    /// `x` is where the text starts and `y` where its baseline is.
+    pub fn text(&mut self, x: f64, y: f64, size: f64, fill: &str, text: &str) {
+        self.shapes.push(format!(
+            r#"<text x="{x}" y="{y}" font-family="monospace" font-size="{size}" fill="{}">{}</text>"#,
+            escape(fill),
+            escape(text)
+        ));
+    }
+
+    pub fn line(&mut self, from: (f64, f64), to: (f64, f64), stroke: &str) {
+        self.shapes.push(format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}"/>"#,
+            from.0,
+            from.1,
+            to.0,
+            to.1,
+            escape(stroke)
+        ));
+    }
+
+    /// A filled polygon, see-through by `opacity` so overlapping ones stay visible.
+    pub fn polygon(&mut self, points: &[(f64, f64)], fill: &str, opacity: f64) {
+        let mut list = String::new();
+        for (x, y) in points {
+            write!(list, "{x},{y} ").expect("Writing to a string can't fail");
+        }
+        self.shapes.push(format!(
+            r#"<polygon points="{}" fill="{}" fill-opacity="{opacity}"/>"#,
+            list.trim_end(),
+            escape(fill)
+        ));
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = self.width,
+            h = self.height
+        )?;
+        for shape in &self.shapes {
+            writeln!(f, "  {shape}")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg() {
+        let mut svg = Svg::new(20.0, 10.5);
+        svg.rect(0.0, 0.0, 20.0, 10.5, "white");
+        svg.text(1.0, 8.0, 10.0, "black", "a<b & \"c\"");
+        svg.polygon(&[(0.0, 0.0), (1.5, 2.0), (3.0, 0.0)], "red", 0.5);
+        svg.line((0.0, 1.0), (20.0, 1.0), "gray");
+        assert_eq!(
+            svg.to_string(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10.5" viewBox="0 0 20 10.5">"#,
+                "\n",
+                r#"  <rect x="0" y="0" width="20" height="10.5" fill="white"/>"#,
+                "\n",
+                r#"  <text x="1" y="8" font-family="monospace" font-size="10" fill="black">a&lt;b &amp; &quot;c&quot;</text>"#,
+                "\n",
+                r#"  <polygon points="0,0 1.5,2 3,0" fill="red" fill-opacity="0.5"/>"#,
+                "\n",
+                r#"  <line x1="0" y1="1" x2="20" y2="1" stroke="gray"/>"#,
+                "\n",
+                "</svg>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_dot_quote() {
+        assert_eq!(dot_quote("AAA"), r#""AAA""#);
+        assert_eq!(dot_quote(r#"a "b" \c"#), r#""a \"b\" \\c""#);
+    }
+}
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc_common::{fuzz::check_round_trip, Solution};
+use libfuzzer_sys::fuzz_target;
+use y2023_day_1::Day1;
+
+// Day 1's lines are only read when solving, so the parts are fuzzed too
+fuzz_target!(|data: &[u8]| {
+    if let Some(input) = check_round_trip::<Day1>(data) {
+        let _ = Day1::part_1(&input);
+        let _ = Day1::part_2(&input);
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_common::fuzz::check_round_trip;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    check_round_trip::<y2023_day_4::Day4>(data);
+});